@@ -27,7 +27,7 @@ fn dump() {
         Cardinality::Single,
     ));
 
-    let vertex_prop_group = PropertyGroup::new(vertex_props, FileType::Parquet, "");
+    let vertex_prop_group = PropertyGroup::new(vertex_props, FileType::Parquet, "").unwrap();
     let mut vertex_prop_groups = PropertyGroupVector::new();
     vertex_prop_groups.add_property_group(vertex_prop_group);
 
@@ -40,7 +40,8 @@ fn dump() {
         vec![],
         "",
         version.clone(),
-    );
+    )
+    .unwrap();
     vertex_info
         .save("/tmp/test_graphar/person.vertex.yml")
         .unwrap();
@@ -72,11 +73,8 @@ fn dump() {
         Cardinality::Single,
     ));
     let mut edge_prop_groups = PropertyGroupVector::new();
-    edge_prop_groups.add_property_group(PropertyGroup::new(
-        edge_props,
-        FileType::Csv,
-        "knows/props",
-    ));
+    edge_prop_groups
+        .add_property_group(PropertyGroup::new(edge_props, FileType::Csv, "knows/props").unwrap());
 
     let edge_info = EdgeInfo::new(
         "person",
@@ -90,7 +88,8 @@ fn dump() {
         edge_prop_groups,
         "",
         version.clone(),
-    );
+    )
+    .unwrap();
     edge_info
         .save("/tmp/test_graphar/person_knows_person.edge.yml")
         .unwrap();
@@ -115,7 +114,8 @@ fn dump() {
         &vec![],
         "./",
         Some(version.clone()),
-    );
+    )
+    .unwrap();
     graph_info.save("/tmp/test_graphar/test.graph.yml").unwrap();
 }
 
@@ -0,0 +1,551 @@
+//! Derive macros for `graphar::schema`. See the `graphar::schema` module for
+//! the supported attributes.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, Ident, LitBool, LitInt, LitStr, Result, Token,
+    Type, meta::ParseNestedMeta, parse_macro_input, punctuated::Punctuated,
+};
+
+#[proc_macro_derive(GraphArVertex, attributes(graphar))]
+pub fn derive_vertex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    vertex(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(GraphArEdge, attributes(graphar))]
+pub fn derive_edge(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    edge(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+const VERTEX_ATTRS: &[&str] = &["type", "chunk_size", "prefix", "file_type"];
+const EDGE_ATTRS: &[&str] = &[
+    "src_type",
+    "edge_type",
+    "dst_type",
+    "chunk_size",
+    "src_chunk_size",
+    "dst_chunk_size",
+    "directed",
+    "adj_lists",
+    "prefix",
+    "file_type",
+];
+
+#[derive(Default)]
+struct StructAttrs {
+    ty: Option<String>,
+    src_type: Option<String>,
+    edge_type: Option<String>,
+    dst_type: Option<String>,
+    chunk_size: Option<i64>,
+    src_chunk_size: Option<i64>,
+    dst_chunk_size: Option<i64>,
+    directed: Option<bool>,
+    adj_lists: Vec<LitStr>,
+    prefix: Option<String>,
+    file_type: Option<LitStr>,
+}
+
+impl StructAttrs {
+    fn parse(input: &DeriveInput, allowed: &[&str]) -> Result<Self> {
+        let mut attrs = Self::default();
+        for attr in graphar_attrs(&input.attrs) {
+            attr.parse_nested_meta(|meta| {
+                let Some(key) = meta.path.get_ident().map(Ident::to_string) else {
+                    return Err(meta.error("expected a graphar attribute"));
+                };
+                if !allowed.contains(&key.as_str()) {
+                    return Err(meta.error(format!("unknown graphar attribute `{key}`")));
+                }
+                match key.as_str() {
+                    "type" => attrs.ty = Some(type_name(&meta)?),
+                    "src_type" => attrs.src_type = Some(type_name(&meta)?),
+                    "edge_type" => attrs.edge_type = Some(type_name(&meta)?),
+                    "dst_type" => attrs.dst_type = Some(type_name(&meta)?),
+                    "chunk_size" => attrs.chunk_size = Some(chunk_size(&meta)?),
+                    "src_chunk_size" => attrs.src_chunk_size = Some(chunk_size(&meta)?),
+                    "dst_chunk_size" => attrs.dst_chunk_size = Some(chunk_size(&meta)?),
+                    "directed" => attrs.directed = Some(meta.value()?.parse::<LitBool>()?.value),
+                    "adj_lists" => {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        let lists = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                        attrs.adj_lists.extend(lists);
+                    }
+                    "prefix" => attrs.prefix = Some(meta.value()?.parse::<LitStr>()?.value()),
+                    "file_type" => attrs.file_type = Some(meta.value()?.parse()?),
+                    _ => unreachable!(),
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    primary: bool,
+    nullable: bool,
+    rename: Option<String>,
+    group: Option<String>,
+    file_type: Option<LitStr>,
+    src: bool,
+    dst: bool,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute], edge: bool) -> Result<Self> {
+        let mut out = Self::default();
+        for attr in graphar_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("primary") {
+                    out.primary = true;
+                } else if meta.path.is_ident("nullable") {
+                    out.nullable = true;
+                } else if meta.path.is_ident("rename") {
+                    out.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("group") {
+                    out.group = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("file_type") {
+                    out.file_type = Some(meta.value()?.parse()?);
+                } else if edge && meta.path.is_ident("src") {
+                    out.src = true;
+                } else if edge && meta.path.is_ident("dst") {
+                    out.dst = true;
+                } else {
+                    return Err(meta.error("unknown graphar field attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+}
+
+fn graphar_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("graphar"))
+}
+
+struct Property {
+    ident: Ident,
+    name: String,
+    ty: Type,
+    primary: bool,
+    nullable: bool,
+}
+
+// Properties sharing a `group` attribute, in order of first appearance.
+struct Group {
+    name: Option<String>,
+    file_type: Option<LitStr>,
+    properties: Vec<Property>,
+}
+
+struct Schema {
+    groups: Vec<Group>,
+    src: Option<Ident>,
+    dst: Option<Ident>,
+}
+
+impl Schema {
+    fn parse(input: &DeriveInput, edge: bool) -> Result<Self> {
+        let fields = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) => &fields.named,
+                _ => {
+                    return Err(Error::new_spanned(
+                        input,
+                        "expected a struct with named fields",
+                    ));
+                }
+            },
+            _ => return Err(Error::new_spanned(input, "expected a struct")),
+        };
+
+        let mut schema = Schema {
+            groups: Vec::new(),
+            src: None,
+            dst: None,
+        };
+        for field in fields {
+            let ident = field.ident.clone().expect("named field");
+            let attrs = FieldAttrs::parse(&field.attrs, edge)?;
+            if attrs.src || attrs.dst {
+                let slot = if attrs.src {
+                    &mut schema.src
+                } else {
+                    &mut schema.dst
+                };
+                if attrs.src && attrs.dst || slot.is_some() {
+                    return Err(Error::new_spanned(
+                        field,
+                        "expected one `src` field and one `dst` field",
+                    ));
+                }
+                *slot = Some(ident);
+                continue;
+            }
+
+            let name = attrs.rename.unwrap_or_else(|| ident.to_string());
+            if schema.properties().any(|property| property.name == name) {
+                let message = format!("duplicate property name `{name}`");
+                return Err(Error::new_spanned(field, message));
+            }
+
+            let group = match schema
+                .groups
+                .iter_mut()
+                .position(|group| group.name == attrs.group)
+            {
+                Some(index) => &mut schema.groups[index],
+                None => {
+                    schema.groups.push(Group {
+                        name: attrs.group.clone(),
+                        file_type: None,
+                        properties: Vec::new(),
+                    });
+                    schema.groups.last_mut().unwrap()
+                }
+            };
+            if let Some(file_type) = attrs.file_type {
+                match &group.file_type {
+                    Some(current) if current.value() != file_type.value() => {
+                        let message = format!(
+                            "conflicting file types for the group: `{}` and `{}`",
+                            current.value(),
+                            file_type.value()
+                        );
+                        return Err(Error::new_spanned(file_type, message));
+                    }
+                    _ => group.file_type = Some(file_type),
+                }
+            }
+            group.properties.push(Property {
+                name,
+                ident,
+                ty: field.ty.clone(),
+                primary: attrs.primary,
+                nullable: attrs.nullable,
+            });
+        }
+
+        if edge && (schema.src.is_none() || schema.dst.is_none()) {
+            return Err(Error::new_spanned(
+                input,
+                "expected fields marked `#[graphar(src)]` and `#[graphar(dst)]`",
+            ));
+        }
+        Ok(schema)
+    }
+
+    fn property_groups(&self, default_file_type: Option<&LitStr>) -> Result<TokenStream2> {
+        let mut groups = Vec::new();
+        for group in &self.groups {
+            let file_type = file_type(group.file_type.as_ref().or(default_file_type))?;
+            // GraphAr derives the group prefix from its property names when
+            // it is empty.
+            let prefix = group
+                .name
+                .as_ref()
+                .map(|name| format!("{name}/"))
+                .unwrap_or_default();
+            let properties = group.properties.iter().map(|property| {
+                let Property {
+                    name,
+                    ty,
+                    primary,
+                    nullable,
+                    ..
+                } = property;
+                quote! {
+                    properties.add_property(::graphar::graph_info::Property::new(
+                        #name,
+                        &<#ty as ::graphar::schema::PropertyType>::data_type(),
+                        #primary,
+                        #nullable || <#ty as ::graphar::schema::PropertyType>::NULLABLE,
+                        ::graphar::graph_info::Cardinality::Single,
+                    ));
+                }
+            });
+            groups.push(quote! {
+                let mut properties = ::graphar::graph_info::PropertyVec::new();
+                #(#properties)*
+                groups.add_property_group(
+                    ::graphar::graph_info::PropertyGroup::new(properties, #file_type, #prefix)?,
+                );
+            });
+        }
+        Ok(quote! {
+            let mut groups = ::graphar::graph_info::PropertyGroupVector::new();
+            #(#groups)*
+        })
+    }
+
+    fn properties(&self) -> impl Iterator<Item = &Property> {
+        self.groups.iter().flat_map(|group| &group.properties)
+    }
+
+    fn add_properties(&self) -> TokenStream2 {
+        let adds = self.properties().map(|Property { ident, name, .. }| {
+            quote! {
+                builder.add_property(::std::string::String::from(#name), self.#ident.clone());
+            }
+        });
+        quote! { #(#adds)* }
+    }
+
+    fn read_properties(&self, from: &Ident) -> Vec<TokenStream2> {
+        self.properties()
+            .map(
+                |Property {
+                     ident, name, ty, ..
+                 }| {
+                    quote! { #ident: #from.property::<#ty>(#name)? }
+                },
+            )
+            .collect()
+    }
+}
+
+fn file_type(lit: Option<&LitStr>) -> Result<TokenStream2> {
+    let variant = match lit.map(LitStr::value).as_deref() {
+        None | Some("parquet") => "Parquet",
+        Some("csv") => "Csv",
+        Some("orc") => "Orc",
+        Some("json") => "Json",
+        Some(other) => {
+            return Err(Error::new_spanned(
+                lit,
+                format!("unknown file type `{other}`, expected csv, parquet, orc or json"),
+            ));
+        }
+    };
+    let variant = format_ident!("{variant}");
+    Ok(quote! { ::graphar::graph_info::FileType::#variant })
+}
+
+fn adj_list_type(lit: &LitStr) -> Result<TokenStream2> {
+    let variant = match lit.value().as_str() {
+        "ordered_by_source" => "OrderedBySource",
+        "ordered_by_dest" => "OrderedByDest",
+        "unordered_by_source" => "UnorderedBySource",
+        "unordered_by_dest" => "UnorderedByDest",
+        other => {
+            return Err(Error::new_spanned(
+                lit,
+                format!(
+                    "unknown adjacency list `{other}`, expected ordered_by_source, \
+                     ordered_by_dest, unordered_by_source or unordered_by_dest"
+                ),
+            ));
+        }
+    };
+    let variant = format_ident!("{variant}");
+    Ok(quote! { ::graphar::graph_info::AdjListType::#variant })
+}
+
+// GraphAr rejects infos with an empty type or a non-positive chunk size, so
+// these are caught at compile time.
+fn type_name(meta: &ParseNestedMeta) -> Result<String> {
+    let lit = meta.value()?.parse::<LitStr>()?;
+    if lit.value().is_empty() {
+        return Err(Error::new_spanned(lit, "expected a non-empty type"));
+    }
+    Ok(lit.value())
+}
+
+fn chunk_size(meta: &ParseNestedMeta) -> Result<i64> {
+    let lit = meta.value()?.parse::<LitInt>()?;
+    let size = lit.base10_parse()?;
+    if size <= 0 {
+        return Err(Error::new_spanned(lit, "expected a positive chunk size"));
+    }
+    Ok(size)
+}
+
+fn required<T>(value: Option<T>, input: &DeriveInput, name: &str) -> Result<T> {
+    value.ok_or_else(|| {
+        Error::new_spanned(
+            input,
+            format!("missing `#[graphar({name} = ..)]` attribute"),
+        )
+    })
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn vertex(input: &DeriveInput) -> Result<TokenStream2> {
+    let attrs = StructAttrs::parse(input, VERTEX_ATTRS)?;
+    let schema = Schema::parse(input, false)?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let ty = attrs.ty.unwrap_or_else(|| snake_case(&ident.to_string()));
+    let chunk_size = required(attrs.chunk_size, input, "chunk_size")?;
+    let prefix = attrs.prefix.unwrap_or_else(|| format!("vertex/{ty}/"));
+    let groups = schema.property_groups(attrs.file_type.as_ref())?;
+    let add_properties = schema.add_properties();
+    let vertex = format_ident!("vertex");
+    let fields = schema.read_properties(&vertex);
+
+    Ok(quote! {
+        impl #impl_generics ::graphar::schema::GraphArVertex for #ident #ty_generics #where_clause {
+            const TYPE: &'static str = #ty;
+
+            fn vertex_info() -> ::graphar::Result<::graphar::graph_info::VertexInfo> {
+                #groups
+                ::graphar::graph_info::VertexInfo::new(
+                    ::std::string::String::from(#ty),
+                    #chunk_size,
+                    groups,
+                    ::std::vec::Vec::new(),
+                    #prefix,
+                    ::graphar::graph_info::InfoVersion::new(1)?,
+                )
+            }
+
+            fn to_builder(&self) -> ::graphar::graph_builder::VertexBuilder {
+                let mut builder = ::graphar::graph_builder::VertexBuilder::new();
+                #add_properties
+                builder
+            }
+
+            fn from_vertex(
+                #vertex: &::graphar::graph_reader::Vertex,
+            ) -> ::graphar::Result<Self> {
+                ::std::result::Result::Ok(Self { #(#fields),* })
+            }
+        }
+    })
+}
+
+fn edge(input: &DeriveInput) -> Result<TokenStream2> {
+    let attrs = StructAttrs::parse(input, EDGE_ATTRS)?;
+    let schema = Schema::parse(input, true)?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let src_type = required(attrs.src_type, input, "src_type")?;
+    let edge_type = required(attrs.edge_type, input, "edge_type")?;
+    let dst_type = required(attrs.dst_type, input, "dst_type")?;
+    let chunk_size = required(attrs.chunk_size, input, "chunk_size")?;
+    let src_chunk_size = required(attrs.src_chunk_size, input, "src_chunk_size")?;
+    let dst_chunk_size = required(attrs.dst_chunk_size, input, "dst_chunk_size")?;
+    let directed = attrs.directed.unwrap_or(true);
+    let prefix = attrs
+        .prefix
+        .unwrap_or_else(|| format!("edge/{src_type}_{edge_type}_{dst_type}/"));
+
+    let adj_lists = if attrs.adj_lists.is_empty() {
+        vec![LitStr::new("ordered_by_source", ident.span())]
+    } else {
+        attrs.adj_lists
+    };
+    let adj_list_file_type = file_type(attrs.file_type.as_ref())?;
+    let adj_lists = adj_lists
+        .iter()
+        .map(|lit| {
+            let ty = adj_list_type(lit)?;
+            let prefix = format!("{}/", lit.value());
+            Ok(quote! {
+                adj_lists.add_adjacent_list(::graphar::graph_info::AdjacentList::new(
+                    #ty,
+                    #adj_list_file_type,
+                    #prefix,
+                ));
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let groups = schema.property_groups(attrs.file_type.as_ref())?;
+    let add_properties = schema.add_properties();
+    let edge = format_ident!("edge");
+    let fields = schema.read_properties(&edge);
+    let src = schema.src.as_ref().unwrap();
+    let dst = schema.dst.as_ref().unwrap();
+
+    Ok(quote! {
+        impl #impl_generics ::graphar::schema::GraphArEdge for #ident #ty_generics #where_clause {
+            const SRC_TYPE: &'static str = #src_type;
+            const EDGE_TYPE: &'static str = #edge_type;
+            const DST_TYPE: &'static str = #dst_type;
+
+            fn edge_info() -> ::graphar::Result<::graphar::graph_info::EdgeInfo> {
+                let mut adj_lists = ::graphar::graph_info::AdjacentListVector::new();
+                #(#adj_lists)*
+                #groups
+                ::graphar::graph_info::EdgeInfo::new(
+                    #src_type,
+                    #edge_type,
+                    #dst_type,
+                    #chunk_size,
+                    #src_chunk_size,
+                    #dst_chunk_size,
+                    #directed,
+                    adj_lists,
+                    groups,
+                    #prefix,
+                    ::graphar::graph_info::InfoVersion::new(1)?,
+                )
+            }
+
+            fn to_builder(&self) -> ::graphar::graph_builder::EdgeBuilder {
+                let mut builder = ::graphar::graph_builder::EdgeBuilder::new(self.#src, self.#dst);
+                #add_properties
+                builder
+            }
+
+            fn from_edge(
+                #edge: &::graphar::graph_reader::Edge,
+            ) -> ::graphar::Result<Self> {
+                ::std::result::Result::Ok(Self {
+                    #src: #edge.src(),
+                    #dst: #edge.dst(),
+                    #(#fields),*
+                })
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn rejects_duplicate_property_names() {
+        let input: DeriveInput = parse_quote! {
+            #[graphar(type = "person", chunk_size = 100)]
+            struct Person {
+                id: i64,
+                #[graphar(rename = "id", group = "other")]
+                other_id: i64,
+            }
+        };
+        let err = vertex(&input).err().unwrap();
+        assert_eq!(err.to_string(), "duplicate property name `id`");
+    }
+}
@@ -3,6 +3,7 @@
 #include <string>
 #include <vector>
 
+#include "graphar/arrow/chunk_reader.h"
 #include "graphar/expression.h"
 #include "graphar/fwd.h"
 #include "graphar/graph_info.h"
@@ -49,6 +50,17 @@ void graph_info_save(const graphar::GraphInfo &graph_info,
                      const std::string &path);
 std::unique_ptr<std::string>
 graph_info_dump(const graphar::GraphInfo &graph_info);
+rust::Vec<rust::String>
+graph_info_extra_info(const graphar::GraphInfo &graph_info);
+std::shared_ptr<graphar::GraphInfo>
+graph_info_with_extra_info(const graphar::GraphInfo &graph_info,
+                           const rust::Vec<rust::String> &extra_info);
+std::shared_ptr<graphar::GraphInfo>
+graph_info_add_vertex(const graphar::GraphInfo &graph_info,
+                      const std::shared_ptr<graphar::VertexInfo> &vertex_info);
+std::shared_ptr<graphar::GraphInfo>
+graph_info_add_edge(const graphar::GraphInfo &graph_info,
+                    const std::shared_ptr<graphar::EdgeInfo> &edge_info);
 
 std::unique_ptr<graphar::Property>
 new_property(const std::string &name,
@@ -76,6 +88,20 @@ void push_property_group(
 // VertexInfo
 void vertex_info_save(const graphar::VertexInfo &v, const std::string &path);
 std::unique_ptr<std::string> vertex_info_dump(const graphar::VertexInfo &v);
+std::shared_ptr<graphar::VertexInfo> vertex_info_add_property_group(
+    const graphar::VertexInfo &v,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group);
+rust::String vertex_info_file_path(
+    const graphar::VertexInfo &v,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group,
+    graphar::IdType chunk_index);
+rust::String vertex_info_path_prefix(
+    const graphar::VertexInfo &v,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group);
+rust::String vertex_info_vertices_num_file_path(const graphar::VertexInfo &v);
+graphar::IdType
+vertex_info_vertices_num(const std::string &prefix,
+                         const std::shared_ptr<graphar::VertexInfo> &v);
 std::shared_ptr<graphar::VertexInfo>
 create_vertex_info(const rust::String &type, graphar::IdType chunk_size,
                    const graphar::PropertyGroupVector &property_group,
@@ -99,6 +125,45 @@ void vertex_add_property_f64(graphar::builder::Vertex &v,
 void vertex_add_property_string(graphar::builder::Vertex &v,
                                 const std::string &name,
                                 const std::string &val);
+void vertex_add_property_date(graphar::builder::Vertex &v,
+                              const std::string &name, int32_t days);
+void vertex_add_property_timestamp(graphar::builder::Vertex &v,
+                                   const std::string &name, int64_t millis);
+void vertex_add_property_list_i32(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const int32_t> val);
+void vertex_add_property_list_i64(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const int64_t> val);
+void vertex_add_property_list_f32(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const float> val);
+void vertex_add_property_list_f64(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const double> val);
+void vertex_add_property_list_string(graphar::builder::Vertex &v,
+                                     const std::string &name,
+                                     const rust::Vec<rust::String> &val);
+void vertex_add_multi_property_i32(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const int32_t> val);
+void vertex_add_multi_property_i64(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const int64_t> val);
+void vertex_add_multi_property_f32(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const float> val);
+void vertex_add_multi_property_f64(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const double> val);
+void vertex_add_multi_property_string(graphar::builder::Vertex &v,
+                                      const std::string &name,
+                                      graphar::Cardinality cardinality,
+                                      const rust::Vec<rust::String> &val);
 
 // builder::VerticesBuilder
 std::shared_ptr<graphar::builder::VerticesBuilder>
@@ -118,6 +183,49 @@ void push_adjacent_list(graphar::AdjacentListVector &v,
 void edge_info_save(const graphar::EdgeInfo &edge_info,
                     const std::string &path);
 std::unique_ptr<std::string> edge_info_dump(const graphar::EdgeInfo &edge_info);
+std::shared_ptr<graphar::EdgeInfo> edge_info_add_property_group(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group);
+std::shared_ptr<graphar::EdgeInfo> edge_info_add_adjacent_list(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::AdjacentList> &adj_list);
+rust::String
+edge_info_vertices_num_file_path(const graphar::EdgeInfo &edge_info,
+                                 graphar::AdjListType adj_list_type);
+rust::String edge_info_edges_num_file_path(const graphar::EdgeInfo &edge_info,
+                                           graphar::IdType vertex_chunk_index,
+                                           graphar::AdjListType adj_list_type);
+rust::String edge_info_adj_list_file_path(const graphar::EdgeInfo &edge_info,
+                                          graphar::IdType vertex_chunk_index,
+                                          graphar::IdType edge_chunk_index,
+                                          graphar::AdjListType adj_list_type);
+rust::String
+edge_info_adj_list_path_prefix(const graphar::EdgeInfo &edge_info,
+                               graphar::AdjListType adj_list_type);
+rust::String
+edge_info_adj_list_offset_file_path(const graphar::EdgeInfo &edge_info,
+                                    graphar::IdType vertex_chunk_index,
+                                    graphar::AdjListType adj_list_type);
+rust::String edge_info_offset_path_prefix(const graphar::EdgeInfo &edge_info,
+                                          graphar::AdjListType adj_list_type);
+rust::String edge_info_property_file_path(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group,
+    graphar::AdjListType adj_list_type, graphar::IdType vertex_chunk_index,
+    graphar::IdType edge_chunk_index);
+rust::String edge_info_property_group_path_prefix(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group,
+    graphar::AdjListType adj_list_type);
+graphar::IdType
+edge_info_vertices_num(const std::string &prefix,
+                       const std::shared_ptr<graphar::EdgeInfo> &edge_info,
+                       graphar::AdjListType adj_list_type);
+graphar::IdType
+edge_info_edges_num(const std::string &prefix,
+                    const std::shared_ptr<graphar::EdgeInfo> &edge_info,
+                    graphar::AdjListType adj_list_type,
+                    graphar::IdType vertex_chunk_index);
 
 // builder::Edge
 std::unique_ptr<graphar::builder::Edge> new_edge(graphar::IdType src_id,
@@ -134,6 +242,25 @@ void edge_add_property_f64(graphar::builder::Edge &v, const std::string &name,
                            double val);
 void edge_add_property_string(graphar::builder::Edge &v,
                               const std::string &name, const std::string &val);
+void edge_add_property_date(graphar::builder::Edge &e, const std::string &name,
+                            int32_t days);
+void edge_add_property_timestamp(graphar::builder::Edge &e,
+                                 const std::string &name, int64_t millis);
+void edge_add_property_list_i32(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const int32_t> val);
+void edge_add_property_list_i64(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const int64_t> val);
+void edge_add_property_list_f32(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const float> val);
+void edge_add_property_list_f64(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const double> val);
+void edge_add_property_list_string(graphar::builder::Edge &e,
+                                   const std::string &name,
+                                   const rust::Vec<rust::String> &val);
 
 // builder::EdgeBuilder
 std::shared_ptr<graphar::builder::EdgesBuilder>
@@ -158,6 +285,22 @@ DECL_VERTEX_PROPERTY_FUNC(f32)
 DECL_VERTEX_PROPERTY_FUNC(f64)
 rust::String vertex_property_string(const graphar::Vertex &vertex,
                                     const std::string &name);
+int32_t vertex_property_date(const graphar::Vertex &vertex,
+                             const std::string &name);
+int64_t vertex_property_timestamp(const graphar::Vertex &vertex,
+                                  const std::string &name);
+
+#define DECL_VERTEX_LIST_PROPERTY_FUNC(type)                                   \
+  rust::Vec<type> vertex_property_list_##type(const graphar::Vertex &vertex,   \
+                                              const std::string &name);
+
+DECL_VERTEX_LIST_PROPERTY_FUNC(i32)
+DECL_VERTEX_LIST_PROPERTY_FUNC(i64)
+DECL_VERTEX_LIST_PROPERTY_FUNC(f32)
+DECL_VERTEX_LIST_PROPERTY_FUNC(f64)
+rust::Vec<rust::String>
+vertex_property_list_string(const graphar::Vertex &vertex,
+                            const std::string &name);
 
 // Edge
 #define DECL_EDGE_PROPERTY_FUNC(type)                                          \
@@ -170,9 +313,24 @@ DECL_EDGE_PROPERTY_FUNC(f32)
 DECL_EDGE_PROPERTY_FUNC(f64)
 rust::String edge_property_string(const graphar::Edge &edge,
                                   const std::string &name);
+int32_t edge_property_date(const graphar::Edge &edge, const std::string &name);
+int64_t edge_property_timestamp(const graphar::Edge &edge,
+                                const std::string &name);
+
+#define DECL_EDGE_LIST_PROPERTY_FUNC(type)                                     \
+  rust::Vec<type> edge_property_list_##type(const graphar::Edge &edge,         \
+                                            const std::string &name);
+
+DECL_EDGE_LIST_PROPERTY_FUNC(i32)
+DECL_EDGE_LIST_PROPERTY_FUNC(i64)
+DECL_EDGE_LIST_PROPERTY_FUNC(f32)
+DECL_EDGE_LIST_PROPERTY_FUNC(f64)
+rust::Vec<rust::String> edge_property_list_string(const graphar::Edge &edge,
+                                                  const std::string &name);
 
 // VertexIter
 std::unique_ptr<graphar::Vertex> vertex_iter_deref(graphar::VertexIter &iter);
+bool vertex_iter_is_valid(graphar::VertexIter &iter, const std::string &name);
 bool vertex_iter_eq(const std::unique_ptr<graphar::VertexIter> &lhs,
                     const std::unique_ptr<graphar::VertexIter> &rhs);
 graphar::IdType vertex_iter_id(graphar::VertexIter &iter);
@@ -188,6 +346,21 @@ double vertex_iter_property_f64(graphar::VertexIter &iter,
                                 const std::string &name);
 rust::String vertex_iter_property_string(graphar::VertexIter &iter,
                                          const std::string &name);
+int32_t vertex_iter_property_date(graphar::VertexIter &iter,
+                                  const std::string &name);
+int64_t vertex_iter_property_timestamp(graphar::VertexIter &iter,
+                                       const std::string &name);
+rust::Vec<int32_t> vertex_iter_property_list_i32(graphar::VertexIter &iter,
+                                                 const std::string &name);
+rust::Vec<int64_t> vertex_iter_property_list_i64(graphar::VertexIter &iter,
+                                                 const std::string &name);
+rust::Vec<float> vertex_iter_property_list_f32(graphar::VertexIter &iter,
+                                               const std::string &name);
+rust::Vec<double> vertex_iter_property_list_f64(graphar::VertexIter &iter,
+                                                const std::string &name);
+rust::Vec<rust::String>
+vertex_iter_property_list_string(graphar::VertexIter &iter,
+                                 const std::string &name);
 bool vertex_iter_has_label(graphar::VertexIter &iter, const std::string &label);
 std::unique_ptr<std::vector<std::string>>
 vertex_iter_labels(graphar::VertexIter &iter);
@@ -195,6 +368,7 @@ void vertex_iter_next(graphar::VertexIter &iter);
 
 // EdgeIter
 std::unique_ptr<graphar::Edge> edge_iter_deref(graphar::EdgeIter &iter);
+bool edge_iter_is_valid(graphar::EdgeIter &iter, const std::string &name);
 bool edge_iter_eq(const std::unique_ptr<graphar::EdgeIter> &lhs,
                   const std::unique_ptr<graphar::EdgeIter> &rhs);
 graphar::IdType edge_iter_source(graphar::EdgeIter &iter);
@@ -208,6 +382,21 @@ float edge_iter_property_f32(graphar::EdgeIter &iter, const std::string &name);
 double edge_iter_property_f64(graphar::EdgeIter &iter, const std::string &name);
 rust::String edge_iter_property_string(graphar::EdgeIter &iter,
                                        const std::string &name);
+int32_t edge_iter_property_date(graphar::EdgeIter &iter,
+                                const std::string &name);
+int64_t edge_iter_property_timestamp(graphar::EdgeIter &iter,
+                                     const std::string &name);
+rust::Vec<int32_t> edge_iter_property_list_i32(graphar::EdgeIter &iter,
+                                               const std::string &name);
+rust::Vec<int64_t> edge_iter_property_list_i64(graphar::EdgeIter &iter,
+                                               const std::string &name);
+rust::Vec<float> edge_iter_property_list_f32(graphar::EdgeIter &iter,
+                                             const std::string &name);
+rust::Vec<double> edge_iter_property_list_f64(graphar::EdgeIter &iter,
+                                              const std::string &name);
+rust::Vec<rust::String>
+edge_iter_property_list_string(graphar::EdgeIter &iter,
+                               const std::string &name);
 void edge_iter_to_begin(graphar::EdgeIter &iter);
 void edge_iter_next(graphar::EdgeIter &iter);
 bool edge_iter_next_src(graphar::EdgeIter &iter);
@@ -256,6 +445,21 @@ std::shared_ptr<graphar::VerticesCollection> vertices_collection_with_property(
     const std::shared_ptr<graphar::GraphInfo> &graph_info,
     const std::string &type, const std::string &property_name,
     const std::shared_ptr<graphar::Expression> &filter);
+std::shared_ptr<graphar::VerticesCollection>
+vertices_collection_with_ids(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                             const std::string &type,
+                             rust::Slice<const graphar::IdType> ids);
+std::shared_ptr<graphar::VerticesCollection>
+vertices_collection_refine_with_labels(
+    const std::shared_ptr<graphar::VerticesCollection> &vc,
+    const std::vector<std::string> &labels);
+std::shared_ptr<graphar::VerticesCollection>
+vertices_collection_refine_with_property(
+    const std::shared_ptr<graphar::VerticesCollection> &vc,
+    const std::string &property_name,
+    const std::shared_ptr<graphar::Expression> &filter);
+std::unique_ptr<std::vector<std::string>>
+new_string_vector(rust::Slice<const rust::Str> values);
 
 // EdgesCollection
 std::unique_ptr<graphar::EdgeIter>
@@ -273,6 +477,11 @@ std::shared_ptr<graphar::EdgesCollection> edges_collection_make(
     const std::string &src_type, const std::string &edge_type,
     const std::string &dst_type, graphar::AdjListType adj_list_type,
     graphar::IdType vertex_chunk_begin, graphar::IdType vertex_chunk_end);
+graphar::IdType
+edges_vertex_chunk_num(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                       const std::string &src_type, const std::string &edge_type,
+                       const std::string &dst_type,
+                       graphar::AdjListType adj_list_type);
 
 // Expression helpers
 std::shared_ptr<graphar::Expression>
@@ -286,6 +495,9 @@ std::shared_ptr<graphar::Expression> expression_literal_f32(float value);
 std::shared_ptr<graphar::Expression> expression_literal_f64(double value);
 std::shared_ptr<graphar::Expression>
 expression_literal_string(const std::string &value);
+std::shared_ptr<graphar::Expression> expression_literal_date(int32_t days);
+std::shared_ptr<graphar::Expression>
+expression_literal_timestamp(int64_t millis);
 std::shared_ptr<graphar::Expression>
 expression_equal(const std::shared_ptr<graphar::Expression> &lhs,
                  const std::shared_ptr<graphar::Expression> &rhs);
@@ -313,4 +525,52 @@ expression_or(const std::shared_ptr<graphar::Expression> &lhs,
 std::shared_ptr<graphar::Expression>
 expression_not(const std::shared_ptr<graphar::Expression> &expr);
 
+// Arrow chunk readers
+// `out_array` and `out_schema` are addresses of arrow C data interface
+// `ArrowArray` and `ArrowSchema` structs owned by the caller.
+std::shared_ptr<graphar::VertexPropertyArrowChunkReader>
+new_vertex_chunk_reader(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                        const std::string &type,
+                        const std::shared_ptr<graphar::PropertyGroup> &group);
+std::shared_ptr<graphar::VertexPropertyArrowChunkReader>
+new_vertex_chunk_reader_by_property(
+    const std::shared_ptr<graphar::GraphInfo> &graph_info,
+    const std::string &type, const std::string &property_name);
+void vertex_chunk_reader_seek(graphar::VertexPropertyArrowChunkReader &reader,
+                              graphar::IdType id);
+bool vertex_chunk_reader_next_chunk(
+    graphar::VertexPropertyArrowChunkReader &reader);
+graphar::IdType
+vertex_chunk_reader_chunk_num(graphar::VertexPropertyArrowChunkReader &reader);
+void vertex_chunk_reader_export(graphar::VertexPropertyArrowChunkReader &reader,
+                                size_t out_array, size_t out_schema);
+
+std::shared_ptr<graphar::AdjListArrowChunkReader>
+new_adj_list_chunk_reader(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                          const std::string &src_type,
+                          const std::string &edge_type,
+                          const std::string &dst_type,
+                          graphar::AdjListType adj_list_type);
+void adj_list_chunk_reader_seek(graphar::AdjListArrowChunkReader &reader,
+                                graphar::IdType offset);
+void adj_list_chunk_reader_seek_src(graphar::AdjListArrowChunkReader &reader,
+                                    graphar::IdType id);
+void adj_list_chunk_reader_seek_dst(graphar::AdjListArrowChunkReader &reader,
+                                    graphar::IdType id);
+bool adj_list_chunk_reader_next_chunk(graphar::AdjListArrowChunkReader &reader);
+void adj_list_chunk_reader_export(graphar::AdjListArrowChunkReader &reader,
+                                  size_t out_array, size_t out_schema);
+
+// Adjacency offsets
+std::shared_ptr<graphar::AdjListOffsetArrowChunkReader>
+new_adj_list_offset_reader(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                           const std::string &src_type,
+                           const std::string &edge_type,
+                           const std::string &dst_type,
+                           graphar::AdjListType adj_list_type);
+bool adj_list_offset_reader_next_chunk(
+    graphar::AdjListOffsetArrowChunkReader &reader);
+rust::Vec<graphar::IdType>
+adj_list_offset_reader_chunk(graphar::AdjListOffsetArrowChunkReader &reader);
+
 } // namespace graphar_rs
@@ -0,0 +1,196 @@
+use std::{
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Errors of the crate. The variants mirror GraphAr's `StatusCode`; errors
+/// raised on the Rust side use the code GraphAr would have used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A vertex type, edge type, property or other key does not exist.
+    Key(Context),
+    /// A value does not have the expected data type.
+    Type(Context),
+    /// Invalid metadata or arguments.
+    Invalid(Context),
+    /// An index out of range.
+    Index(Context),
+    OutOfMemory(Context),
+    /// Reading or writing files failed.
+    Io(Context),
+    /// A malformed `.yml` info file.
+    Yaml(Context),
+    Arrow(Context),
+    Unknown(Context),
+}
+
+/// What an [`Error`] is about.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Context {
+    pub message: String,
+    pub path: Option<PathBuf>,
+    pub property: Option<String>,
+}
+
+impl Error {
+    pub(crate) fn key(message: impl Into<String>) -> Self {
+        Error::Key(Context::new(message))
+    }
+
+    pub(crate) fn type_error(message: impl Into<String>) -> Self {
+        Error::Type(Context::new(message))
+    }
+
+    pub(crate) fn invalid(message: impl Into<String>) -> Self {
+        Error::Invalid(Context::new(message))
+    }
+
+    #[cfg_attr(not(feature = "tokio"), allow(dead_code))]
+    pub(crate) fn unknown(message: impl Into<String>) -> Self {
+        Error::Unknown(Context::new(message))
+    }
+
+    pub fn context(&self) -> &Context {
+        match self {
+            Error::Key(context)
+            | Error::Type(context)
+            | Error::Invalid(context)
+            | Error::Index(context)
+            | Error::OutOfMemory(context)
+            | Error::Io(context)
+            | Error::Yaml(context)
+            | Error::Arrow(context)
+            | Error::Unknown(context) => context,
+        }
+    }
+
+    fn context_mut(&mut self) -> &mut Context {
+        match self {
+            Error::Key(context)
+            | Error::Type(context)
+            | Error::Invalid(context)
+            | Error::Index(context)
+            | Error::OutOfMemory(context)
+            | Error::Io(context)
+            | Error::Yaml(context)
+            | Error::Arrow(context)
+            | Error::Unknown(context) => context,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.context().message
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.context().path.as_deref()
+    }
+
+    pub fn property(&self) -> Option<&str> {
+        self.context().property.as_deref()
+    }
+
+    /// Whether retrying may succeed, as for I/O and allocation failures.
+    /// Metadata and type errors are not transient.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Error::Io(_) | Error::OutOfMemory(_))
+    }
+
+    /// Sets the path the error is about, unless it already has one.
+    pub(crate) fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        let context = self.context_mut();
+        context
+            .path
+            .get_or_insert_with(|| path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the property the error is about, unless it already has one.
+    pub(crate) fn with_property(mut self, name: &str) -> Self {
+        let context = self.context_mut();
+        context.property.get_or_insert_with(|| name.to_string());
+        self
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Error::Key(_) => "key error",
+            Error::Type(_) => "type error",
+            Error::Invalid(_) => "invalid",
+            Error::Index(_) => "index error",
+            Error::OutOfMemory(_) => "out of memory",
+            Error::Io(_) => "I/O error",
+            Error::Yaml(_) => "YAML error",
+            Error::Arrow(_) => "arrow error",
+            Error::Unknown(_) => "unknown error",
+        }
+    }
+}
+
+impl Context {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Self::default()
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let context = self.context();
+        write!(f, "{}: {}", self.kind(), context.message)?;
+        if let Some(property) = &context.property {
+            write!(f, " (property `{property}`)")?;
+        }
+        if let Some(path) = &context.path {
+            write!(f, " (path `{}`)", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<cxx::Exception> for Error {
+    fn from(err: cxx::Exception) -> Self {
+        // The shim prefixes messages with GraphAr's status code, as
+        // `[<code>] <message>`. See `ThrowStatus` in `src/graphar_rs.cc`.
+        let what = err.what();
+        let Some((code, message)) = what
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once("] "))
+        else {
+            return Error::Unknown(Context::new(what));
+        };
+        let context = Context::new(message);
+        match code {
+            "KeyError" => Error::Key(context),
+            "TypeError" => Error::Type(context),
+            "Invalid" => Error::Invalid(context),
+            "IndexError" => Error::Index(context),
+            "OutOfMemory" => Error::OutOfMemory(context),
+            "IOError" => Error::Io(context),
+            "YamlError" => Error::Yaml(context),
+            "ArrowError" => Error::Arrow(context),
+            "UnknownError" => Error::Unknown(context),
+            _ => Error::Unknown(Context::new(what)),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(Context::new(err.to_string()))
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<arrow::error::ArrowError> for Error {
+    fn from(err: arrow::error::ArrowError) -> Self {
+        Error::Arrow(Context::new(err.to_string()))
+    }
+}
@@ -173,7 +173,6 @@ pub(crate) mod graphar {
         fn GetLabels(&self) -> &CxxVector<CxxString>;
         fn GetPrefix(&self) -> &CxxString;
         fn version(&self) -> &SharedPtr<ConstInfoVersion>;
-        // TODO(GetExtraInfo)
         fn GetVertexInfo(&self, type_: &CxxString) -> SharedPtr<VertexInfo>;
         fn GetEdgeInfo(
             &self,
@@ -194,8 +193,7 @@ pub(crate) mod graphar {
         // fn GetEdgeInfoByIndex(&self, index: i32) -> SharedPtr<EdgeInfo>; // TODO(graphar use `int`)
         fn GetVertexInfos(&self) -> &CxxVector<SharedVertexInfo>;
         fn GetEdgeInfos(&self) -> &CxxVector<SharedEdgeInfo>;
-        // TODO(AddVertex)
-        // TODO(AddEdge)
+        fn IsValidated(&self) -> bool;
 
         #[namespace = "graphar_rs"]
         fn load_graph_info(path: &CxxString) -> Result<SharedPtr<GraphInfo>>;
@@ -213,6 +211,24 @@ pub(crate) mod graphar {
         fn graph_info_save(graph_info: &GraphInfo, path: &CxxString) -> Result<()>;
         #[namespace = "graphar_rs"]
         fn graph_info_dump(graph_info: &GraphInfo) -> Result<UniquePtr<CxxString>>;
+        // Keys and values alternate.
+        #[namespace = "graphar_rs"]
+        fn graph_info_extra_info(graph_info: &GraphInfo) -> Vec<String>;
+        #[namespace = "graphar_rs"]
+        fn graph_info_with_extra_info(
+            graph_info: &GraphInfo,
+            extra_info: &Vec<String>,
+        ) -> SharedPtr<GraphInfo>;
+        #[namespace = "graphar_rs"]
+        fn graph_info_add_vertex(
+            graph_info: &GraphInfo,
+            vertex_info: &SharedPtr<VertexInfo>,
+        ) -> Result<SharedPtr<GraphInfo>>;
+        #[namespace = "graphar_rs"]
+        fn graph_info_add_edge(
+            graph_info: &GraphInfo,
+            edge_info: &SharedPtr<EdgeInfo>,
+        ) -> Result<SharedPtr<GraphInfo>>;
     }
 
     // `Property`
@@ -284,7 +300,6 @@ pub(crate) mod graphar {
     unsafe extern "C++" {
         type VertexInfo;
 
-        // TODO(AddPropertyGroup)
         fn GetType(&self) -> &CxxString;
         fn GetChunkSize(&self) -> i64;
         fn GetPrefix(&self) -> &CxxString;
@@ -294,6 +309,7 @@ pub(crate) mod graphar {
         fn GetPropertyGroups(&self) -> &CxxVector<SharedPropertyGroup>;
         fn GetPropertyGroup(&self, name: &CxxString) -> SharedPtr<PropertyGroup>;
         fn GetPropertyGroupByIndex(&self, index: i32) -> SharedPtr<PropertyGroup>; // TODO(graphar use `int`)
+        fn IsValidated(&self) -> bool;
 
         // TODO
 
@@ -310,6 +326,29 @@ pub(crate) mod graphar {
         fn vertex_info_save(vertex_info: &VertexInfo, path: &CxxString) -> Result<()>;
         #[namespace = "graphar_rs"]
         fn vertex_info_dump(vertex_info: &VertexInfo) -> Result<UniquePtr<CxxString>>;
+        #[namespace = "graphar_rs"]
+        fn vertex_info_add_property_group(
+            vertex_info: &VertexInfo,
+            property_group: &SharedPtr<PropertyGroup>,
+        ) -> Result<SharedPtr<VertexInfo>>;
+        #[namespace = "graphar_rs"]
+        fn vertex_info_file_path(
+            vertex_info: &VertexInfo,
+            property_group: &SharedPtr<PropertyGroup>,
+            chunk_index: i64,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn vertex_info_path_prefix(
+            vertex_info: &VertexInfo,
+            property_group: &SharedPtr<PropertyGroup>,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn vertex_info_vertices_num_file_path(vertex_info: &VertexInfo) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn vertex_info_vertices_num(
+            prefix: &CxxString,
+            vertex_info: &SharedPtr<VertexInfo>,
+        ) -> Result<i64>;
 
         #[namespace = "graphar_rs"]
         fn create_vertex_info(
@@ -346,7 +385,79 @@ pub(crate) mod graphar {
             name: &CxxString,
             val: &CxxString,
         );
-        // TODO(list date timestamp)
+        #[namespace = "graphar_rs"]
+        fn vertex_add_property_date(vertex: Pin<&mut VertexBuilder>, name: &CxxString, days: i32);
+        #[namespace = "graphar_rs"]
+        fn vertex_add_property_timestamp(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            millis: i64,
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_property_list_i32(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            val: &[i32],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_property_list_i64(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            val: &[i64],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_property_list_f32(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            val: &[f32],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_property_list_f64(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            val: &[f64],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_property_list_string(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            val: &Vec<String>,
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_multi_property_i32(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            cardinality: Cardinality,
+            val: &[i32],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_multi_property_i64(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            cardinality: Cardinality,
+            val: &[i64],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_multi_property_f32(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            cardinality: Cardinality,
+            val: &[f32],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_multi_property_f64(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            cardinality: Cardinality,
+            val: &[f64],
+        );
+        #[namespace = "graphar_rs"]
+        fn vertex_add_multi_property_string(
+            vertex: Pin<&mut VertexBuilder>,
+            name: &CxxString,
+            cardinality: Cardinality,
+            val: &Vec<String>,
+        );
     }
 
     // `builder::VerticesBuilder`
@@ -416,7 +527,7 @@ pub(crate) mod graphar {
         fn GetPropertyGroups(&self) -> &CxxVector<SharedPropertyGroup>;
         fn GetPropertyGroup(&self, property: &CxxString) -> SharedPtr<PropertyGroup>;
         fn GetPropertyGroupByIndex(&self, index: i32) -> SharedPtr<PropertyGroup>; // TODO(graphar use `int`)
-        // Skip *FilePath
+        fn IsValidated(&self) -> bool;
 
         #[allow(clippy::too_many_arguments)]
         fn CreateEdgeInfo(
@@ -437,13 +548,77 @@ pub(crate) mod graphar {
         fn edge_info_save(edge_info: &EdgeInfo, path: &CxxString) -> Result<()>;
         #[namespace = "graphar_rs"]
         fn edge_info_dump(edge_info: &EdgeInfo) -> Result<UniquePtr<CxxString>>;
-        // #[namespace = "graphar_rs"]
-        // fn edge_info_get_vertices_num_file_path(adj_list_type: AdjListType) -> Result<String>;
-        // #[namespace = "graphar_rs"]
-        // fn edge_info_get_edges_num_file_path(
-        //     vertex_chunk_index: i64,
-        //     adj_list_type: AdjListType,
-        // ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_add_property_group(
+            edge_info: &EdgeInfo,
+            property_group: &SharedPtr<PropertyGroup>,
+        ) -> Result<SharedPtr<EdgeInfo>>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_add_adjacent_list(
+            edge_info: &EdgeInfo,
+            adj_list: &SharedPtr<AdjacentList>,
+        ) -> Result<SharedPtr<EdgeInfo>>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_vertices_num_file_path(
+            edge_info: &EdgeInfo,
+            adj_list_type: AdjListType,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_edges_num_file_path(
+            edge_info: &EdgeInfo,
+            vertex_chunk_index: i64,
+            adj_list_type: AdjListType,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_adj_list_file_path(
+            edge_info: &EdgeInfo,
+            vertex_chunk_index: i64,
+            edge_chunk_index: i64,
+            adj_list_type: AdjListType,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_adj_list_path_prefix(
+            edge_info: &EdgeInfo,
+            adj_list_type: AdjListType,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_adj_list_offset_file_path(
+            edge_info: &EdgeInfo,
+            vertex_chunk_index: i64,
+            adj_list_type: AdjListType,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_offset_path_prefix(
+            edge_info: &EdgeInfo,
+            adj_list_type: AdjListType,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_property_file_path(
+            edge_info: &EdgeInfo,
+            property_group: &SharedPtr<PropertyGroup>,
+            adj_list_type: AdjListType,
+            vertex_chunk_index: i64,
+            edge_chunk_index: i64,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_property_group_path_prefix(
+            edge_info: &EdgeInfo,
+            property_group: &SharedPtr<PropertyGroup>,
+            adj_list_type: AdjListType,
+        ) -> Result<String>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_vertices_num(
+            prefix: &CxxString,
+            edge_info: &SharedPtr<EdgeInfo>,
+            adj_list_type: AdjListType,
+        ) -> Result<i64>;
+        #[namespace = "graphar_rs"]
+        fn edge_info_edges_num(
+            prefix: &CxxString,
+            edge_info: &SharedPtr<EdgeInfo>,
+            adj_list_type: AdjListType,
+            vertex_chunk_index: i64,
+        ) -> Result<i64>;
     }
 
     // `builder::Edge`
@@ -466,7 +641,24 @@ pub(crate) mod graphar {
         fn edge_add_property_f64(edge: Pin<&mut EdgeBuilder>, name: &CxxString, val: f64);
         #[namespace = "graphar_rs"]
         fn edge_add_property_string(edge: Pin<&mut EdgeBuilder>, name: &CxxString, val: &CxxString);
-        // TODO(list date timestamp)
+        #[namespace = "graphar_rs"]
+        fn edge_add_property_date(edge: Pin<&mut EdgeBuilder>, name: &CxxString, days: i32);
+        #[namespace = "graphar_rs"]
+        fn edge_add_property_timestamp(edge: Pin<&mut EdgeBuilder>, name: &CxxString, millis: i64);
+        #[namespace = "graphar_rs"]
+        fn edge_add_property_list_i32(edge: Pin<&mut EdgeBuilder>, name: &CxxString, val: &[i32]);
+        #[namespace = "graphar_rs"]
+        fn edge_add_property_list_i64(edge: Pin<&mut EdgeBuilder>, name: &CxxString, val: &[i64]);
+        #[namespace = "graphar_rs"]
+        fn edge_add_property_list_f32(edge: Pin<&mut EdgeBuilder>, name: &CxxString, val: &[f32]);
+        #[namespace = "graphar_rs"]
+        fn edge_add_property_list_f64(edge: Pin<&mut EdgeBuilder>, name: &CxxString, val: &[f64]);
+        #[namespace = "graphar_rs"]
+        fn edge_add_property_list_string(
+            edge: Pin<&mut EdgeBuilder>,
+            name: &CxxString,
+            val: &Vec<String>,
+        );
     }
 
     // `builder::EdgesBuilder`
@@ -503,6 +695,8 @@ pub(crate) mod graphar {
         fn expression_literal_f32(value: f32) -> SharedPtr<Expression>;
         fn expression_literal_f64(value: f64) -> SharedPtr<Expression>;
         fn expression_literal_string(value: &CxxString) -> SharedPtr<Expression>;
+        fn expression_literal_date(days: i32) -> SharedPtr<Expression>;
+        fn expression_literal_timestamp(millis: i64) -> SharedPtr<Expression>;
         fn expression_equal(
             lhs: &SharedPtr<Expression>,
             rhs: &SharedPtr<Expression>,
@@ -553,7 +747,16 @@ pub(crate) mod graphar {
         fn vertex_property_f32(vertex: &Vertex, property: &CxxString) -> Result<f32>;
         fn vertex_property_f64(vertex: &Vertex, property: &CxxString) -> Result<f64>;
         fn vertex_property_string(vertex: &Vertex, property: &CxxString) -> Result<String>;
-        // TODO(list, date, timestamp)
+        fn vertex_property_date(vertex: &Vertex, property: &CxxString) -> Result<i32>;
+        fn vertex_property_timestamp(vertex: &Vertex, property: &CxxString) -> Result<i64>;
+        fn vertex_property_list_i32(vertex: &Vertex, property: &CxxString) -> Result<Vec<i32>>;
+        fn vertex_property_list_i64(vertex: &Vertex, property: &CxxString) -> Result<Vec<i64>>;
+        fn vertex_property_list_f32(vertex: &Vertex, property: &CxxString) -> Result<Vec<f32>>;
+        fn vertex_property_list_f64(vertex: &Vertex, property: &CxxString) -> Result<Vec<f64>>;
+        fn vertex_property_list_string(
+            vertex: &Vertex,
+            property: &CxxString,
+        ) -> Result<Vec<String>>;
     }
 
     #[namespace = "graphar"]
@@ -573,7 +776,13 @@ pub(crate) mod graphar {
         fn edge_property_f32(edge: &Edge, property: &CxxString) -> Result<f32>;
         fn edge_property_f64(edge: &Edge, property: &CxxString) -> Result<f64>;
         fn edge_property_string(edge: &Edge, property: &CxxString) -> Result<String>;
-        // TODO(list, date, timestamp)
+        fn edge_property_date(edge: &Edge, property: &CxxString) -> Result<i32>;
+        fn edge_property_timestamp(edge: &Edge, property: &CxxString) -> Result<i64>;
+        fn edge_property_list_i32(edge: &Edge, property: &CxxString) -> Result<Vec<i32>>;
+        fn edge_property_list_i64(edge: &Edge, property: &CxxString) -> Result<Vec<i64>>;
+        fn edge_property_list_f32(edge: &Edge, property: &CxxString) -> Result<Vec<f32>>;
+        fn edge_property_list_f64(edge: &Edge, property: &CxxString) -> Result<Vec<f64>>;
+        fn edge_property_list_string(edge: &Edge, property: &CxxString) -> Result<Vec<String>>;
     }
 
     // `VertexIter`
@@ -584,6 +793,7 @@ pub(crate) mod graphar {
     #[namespace = "graphar_rs"]
     unsafe extern "C++" {
         fn vertex_iter_deref(iter: Pin<&mut VertexIter>) -> UniquePtr<Vertex>;
+        fn vertex_iter_is_valid(iter: Pin<&mut VertexIter>, name: &CxxString) -> bool;
         fn vertex_iter_eq(lhs: &UniquePtr<VertexIter>, rhs: &UniquePtr<VertexIter>) -> bool;
         fn vertex_iter_id(iter: Pin<&mut VertexIter>) -> i64;
         fn vertex_iter_property_bool(iter: Pin<&mut VertexIter>, name: &CxxString) -> Result<bool>;
@@ -595,6 +805,31 @@ pub(crate) mod graphar {
             iter: Pin<&mut VertexIter>,
             name: &CxxString,
         ) -> Result<String>;
+        fn vertex_iter_property_date(iter: Pin<&mut VertexIter>, name: &CxxString) -> Result<i32>;
+        fn vertex_iter_property_timestamp(
+            iter: Pin<&mut VertexIter>,
+            name: &CxxString,
+        ) -> Result<i64>;
+        fn vertex_iter_property_list_i32(
+            iter: Pin<&mut VertexIter>,
+            name: &CxxString,
+        ) -> Result<Vec<i32>>;
+        fn vertex_iter_property_list_i64(
+            iter: Pin<&mut VertexIter>,
+            name: &CxxString,
+        ) -> Result<Vec<i64>>;
+        fn vertex_iter_property_list_f32(
+            iter: Pin<&mut VertexIter>,
+            name: &CxxString,
+        ) -> Result<Vec<f32>>;
+        fn vertex_iter_property_list_f64(
+            iter: Pin<&mut VertexIter>,
+            name: &CxxString,
+        ) -> Result<Vec<f64>>;
+        fn vertex_iter_property_list_string(
+            iter: Pin<&mut VertexIter>,
+            name: &CxxString,
+        ) -> Result<Vec<String>>;
         fn vertex_iter_has_label(iter: Pin<&mut VertexIter>, label: &CxxString) -> Result<bool>;
         fn vertex_iter_labels(
             iter: Pin<&mut VertexIter>,
@@ -618,6 +853,7 @@ pub(crate) mod graphar {
     #[namespace = "graphar_rs"]
     unsafe extern "C++" {
         fn edge_iter_deref(iter: Pin<&mut EdgeIter>) -> UniquePtr<Edge>;
+        fn edge_iter_is_valid(iter: Pin<&mut EdgeIter>, name: &CxxString) -> bool;
         fn edge_iter_eq(lhs: &UniquePtr<EdgeIter>, rhs: &UniquePtr<EdgeIter>) -> bool;
         fn edge_iter_property_bool(iter: Pin<&mut EdgeIter>, name: &CxxString) -> Result<bool>;
         fn edge_iter_property_i32(iter: Pin<&mut EdgeIter>, name: &CxxString) -> Result<i32>;
@@ -625,6 +861,28 @@ pub(crate) mod graphar {
         fn edge_iter_property_f32(iter: Pin<&mut EdgeIter>, name: &CxxString) -> Result<f32>;
         fn edge_iter_property_f64(iter: Pin<&mut EdgeIter>, name: &CxxString) -> Result<f64>;
         fn edge_iter_property_string(iter: Pin<&mut EdgeIter>, name: &CxxString) -> Result<String>;
+        fn edge_iter_property_date(iter: Pin<&mut EdgeIter>, name: &CxxString) -> Result<i32>;
+        fn edge_iter_property_timestamp(iter: Pin<&mut EdgeIter>, name: &CxxString) -> Result<i64>;
+        fn edge_iter_property_list_i32(
+            iter: Pin<&mut EdgeIter>,
+            name: &CxxString,
+        ) -> Result<Vec<i32>>;
+        fn edge_iter_property_list_i64(
+            iter: Pin<&mut EdgeIter>,
+            name: &CxxString,
+        ) -> Result<Vec<i64>>;
+        fn edge_iter_property_list_f32(
+            iter: Pin<&mut EdgeIter>,
+            name: &CxxString,
+        ) -> Result<Vec<f32>>;
+        fn edge_iter_property_list_f64(
+            iter: Pin<&mut EdgeIter>,
+            name: &CxxString,
+        ) -> Result<Vec<f64>>;
+        fn edge_iter_property_list_string(
+            iter: Pin<&mut EdgeIter>,
+            name: &CxxString,
+        ) -> Result<Vec<String>>;
         fn edge_iter_next(iter: Pin<&mut EdgeIter>);
         fn edge_iter_to_begin(iter: Pin<&mut EdgeIter>);
         fn edge_iter_next_src(iter: Pin<&mut EdgeIter>) -> bool;
@@ -697,6 +955,21 @@ pub(crate) mod graphar {
             property_name: &CxxString,
             filter: &SharedPtr<Expression>,
         ) -> Result<SharedPtr<VerticesCollection>>;
+        fn vertices_collection_with_ids(
+            graph_info: &SharedPtr<GraphInfo>,
+            type_: &CxxString,
+            ids: &[i64],
+        ) -> Result<SharedPtr<VerticesCollection>>;
+        fn vertices_collection_refine_with_labels(
+            collection: &SharedPtr<VerticesCollection>,
+            labels: &CxxVector<CxxString>,
+        ) -> Result<SharedPtr<VerticesCollection>>;
+        fn vertices_collection_refine_with_property(
+            collection: &SharedPtr<VerticesCollection>,
+            property_name: &CxxString,
+            filter: &SharedPtr<Expression>,
+        ) -> Result<SharedPtr<VerticesCollection>>;
+        fn new_string_vector(values: &[&str]) -> UniquePtr<CxxVector<CxxString>>;
         // TODO(more static function)
     }
 
@@ -729,13 +1002,149 @@ pub(crate) mod graphar {
             vertex_chunk_begin: i64,
             vertex_chunk_end: i64,
         ) -> Result<SharedPtr<EdgesCollection>>;
+        // Number of vertex chunks the adjacency list is partitioned into.
+        fn edges_vertex_chunk_num(
+            graph_info: &SharedPtr<GraphInfo>,
+            src_type: &CxxString,
+            edge_type: &CxxString,
+            dst_type: &CxxString,
+            adj_list_type: AdjListType,
+        ) -> Result<i64>;
+    }
+
+    // Arrow chunk readers
+    #[namespace = "graphar"]
+    unsafe extern "C++" {
+        type VertexPropertyArrowChunkReader;
+        type AdjListArrowChunkReader;
+    }
+    #[namespace = "graphar_rs"]
+    unsafe extern "C++" {
+        fn new_vertex_chunk_reader(
+            graph_info: &SharedPtr<GraphInfo>,
+            type_: &CxxString,
+            group: &SharedPtr<PropertyGroup>,
+        ) -> Result<SharedPtr<VertexPropertyArrowChunkReader>>;
+        fn new_vertex_chunk_reader_by_property(
+            graph_info: &SharedPtr<GraphInfo>,
+            type_: &CxxString,
+            property_name: &CxxString,
+        ) -> Result<SharedPtr<VertexPropertyArrowChunkReader>>;
+        fn vertex_chunk_reader_seek(
+            reader: Pin<&mut VertexPropertyArrowChunkReader>,
+            id: i64,
+        ) -> Result<()>;
+        fn vertex_chunk_reader_next_chunk(
+            reader: Pin<&mut VertexPropertyArrowChunkReader>,
+        ) -> Result<bool>;
+        fn vertex_chunk_reader_chunk_num(reader: Pin<&mut VertexPropertyArrowChunkReader>) -> i64;
+        // `out_array` and `out_schema` are the addresses of an
+        // `FFI_ArrowArray` and an `FFI_ArrowSchema` to export into.
+        unsafe fn vertex_chunk_reader_export(
+            reader: Pin<&mut VertexPropertyArrowChunkReader>,
+            out_array: usize,
+            out_schema: usize,
+        ) -> Result<()>;
+
+        fn new_adj_list_chunk_reader(
+            graph_info: &SharedPtr<GraphInfo>,
+            src_type: &CxxString,
+            edge_type: &CxxString,
+            dst_type: &CxxString,
+            adj_list_type: AdjListType,
+        ) -> Result<SharedPtr<AdjListArrowChunkReader>>;
+        fn adj_list_chunk_reader_seek(
+            reader: Pin<&mut AdjListArrowChunkReader>,
+            offset: i64,
+        ) -> Result<()>;
+        fn adj_list_chunk_reader_seek_src(
+            reader: Pin<&mut AdjListArrowChunkReader>,
+            id: i64,
+        ) -> Result<()>;
+        fn adj_list_chunk_reader_seek_dst(
+            reader: Pin<&mut AdjListArrowChunkReader>,
+            id: i64,
+        ) -> Result<()>;
+        fn adj_list_chunk_reader_next_chunk(
+            reader: Pin<&mut AdjListArrowChunkReader>,
+        ) -> Result<bool>;
+        // `out_array` and `out_schema` are the addresses of an
+        // `FFI_ArrowArray` and an `FFI_ArrowSchema` to export into.
+        unsafe fn adj_list_chunk_reader_export(
+            reader: Pin<&mut AdjListArrowChunkReader>,
+            out_array: usize,
+            out_schema: usize,
+        ) -> Result<()>;
+    }
+
+    // Adjacency offsets
+    #[namespace = "graphar"]
+    unsafe extern "C++" {
+        type AdjListOffsetArrowChunkReader;
+    }
+    #[namespace = "graphar_rs"]
+    unsafe extern "C++" {
+        fn new_adj_list_offset_reader(
+            graph_info: &SharedPtr<GraphInfo>,
+            src_type: &CxxString,
+            edge_type: &CxxString,
+            dst_type: &CxxString,
+            adj_list_type: AdjListType,
+        ) -> Result<SharedPtr<AdjListOffsetArrowChunkReader>>;
+        fn adj_list_offset_reader_next_chunk(
+            reader: Pin<&mut AdjListOffsetArrowChunkReader>,
+        ) -> Result<bool>;
+        fn adj_list_offset_reader_chunk(
+            reader: Pin<&mut AdjListOffsetArrowChunkReader>,
+        ) -> Result<Vec<i64>>;
     }
 }
 
+// Thread safety of the C++ types handed out through `SharedPtr`/`UniquePtr`.
+//
+// SAFETY: GraphAr infos, data types and expressions are immutable once built
+// and only expose const accessors.
+unsafe impl Send for graphar::DataType {}
+unsafe impl Sync for graphar::DataType {}
+unsafe impl Send for graphar::ConstInfoVersion {}
+unsafe impl Sync for graphar::ConstInfoVersion {}
+unsafe impl Send for graphar::PropertyGroup {}
+unsafe impl Sync for graphar::PropertyGroup {}
+unsafe impl Send for graphar::AdjacentList {}
+unsafe impl Sync for graphar::AdjacentList {}
+unsafe impl Send for graphar::VertexInfo {}
+unsafe impl Sync for graphar::VertexInfo {}
+unsafe impl Send for graphar::EdgeInfo {}
+unsafe impl Sync for graphar::EdgeInfo {}
+unsafe impl Send for graphar::GraphInfo {}
+unsafe impl Sync for graphar::GraphInfo {}
+unsafe impl Send for graphar::Expression {}
+unsafe impl Sync for graphar::Expression {}
+
+// SAFETY: a vertex or edge is a snapshot of one row with const accessors.
+unsafe impl Send for graphar::Vertex {}
+unsafe impl Sync for graphar::Vertex {}
+unsafe impl Send for graphar::Edge {}
+unsafe impl Sync for graphar::Edge {}
+
+// SAFETY: iterators and chunk readers own their chunk readers and cached
+// chunks; what they share with their collection (infos, the arrow file
+// system, immutable arrow tables) is safe to use from several threads. They
+// are only mutated through `Pin<&mut _>`, i.e. by a single owner, and the
+// chunk readers have no methods taking `&self` at all.
+unsafe impl Send for graphar::VertexIter {}
+unsafe impl Send for graphar::EdgeIter {}
+unsafe impl Send for graphar::VertexPropertyArrowChunkReader {}
+unsafe impl Sync for graphar::VertexPropertyArrowChunkReader {}
+unsafe impl Send for graphar::AdjListArrowChunkReader {}
+unsafe impl Sync for graphar::AdjListArrowChunkReader {}
+unsafe impl Send for graphar::AdjListOffsetArrowChunkReader {}
+unsafe impl Sync for graphar::AdjListOffsetArrowChunkReader {}
+
 #[cfg(test)]
 mod tests {
     use super::graphar::*;
-    use crate::graph_info::GraphInfo;
+    use crate::{graph_info::GraphInfo, graph_reader::col};
     use cxx::let_cxx_string;
     use std::path::{Path, PathBuf};
 
@@ -758,17 +1167,11 @@ mod tests {
         let_cxx_string!(person = "person");
         let mut vc = vertices_collection_make(&gi.inner, &person).unwrap();
 
-        // Build expression: gender == "male"
         let_cxx_string!(gender = "gender");
-        let_cxx_string!(gender_name = "gender");
-        let lhs = expression_property(&gender_name);
-        let_cxx_string!(male = "male");
-        let rhs = expression_literal_string(&male);
-        let expr = expression_equal(&lhs, &rhs);
-
-        let ids = unsafe {
-            filter_by_property_name(vc.pin_mut_unchecked(), &gender, expr.clone()).unwrap()
-        };
+        let expr = col("gender").eq("male").into();
+
+        let ids =
+            unsafe { filter_by_property_name(vc.pin_mut_unchecked(), &gender, expr).unwrap() };
         // `grep -r ",male" incubator-graphar-testing/ldbc_sample/csv/vertex/person/firstName_lastName_gender/ | wc -l`
         assert_eq!(ids.len(), 449);
     }
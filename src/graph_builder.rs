@@ -1,8 +1,12 @@
-use std::path::Path;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
 
 use cxx::{SharedPtr, UniquePtr, let_cxx_string};
 
 use crate::{
+    Error, Result,
     ffi::{
         self,
         graphar::{
@@ -12,9 +16,14 @@ use crate::{
             vertex_add_property_string, vertices_dump,
         },
     },
-    graph_info::{AdjListType, EdgeInfo, VertexInfo},
+    graph_info::{AdjListType, Cardinality, EdgeInfo, PropertyGroup, VertexInfo},
+    types::{Date, Timestamp},
 };
 
+#[diagnostic::on_unimplemented(
+    message = "`{T}` cannot be written as a GraphAr property",
+    note = "lists are supported for `i32`, `i64`, `f32`, `f64` and `String` elements only"
+)]
 pub trait SupportedDataType<T> {
     fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: T);
     fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: T);
@@ -94,19 +103,260 @@ impl SupportedDataType<String> for () {
     }
 }
 
-impl<T> SupportedDataType<Vec<T>> for ()
+impl SupportedDataType<Date> for () {
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Date) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_property_date(vertex.inner.pin_mut(), &name, val.days());
+    }
+
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Date) {
+        let_cxx_string!(name = name);
+        ffi::graphar::edge_add_property_date(edge.inner.pin_mut(), &name, val.days());
+    }
+}
+
+impl SupportedDataType<Timestamp> for () {
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Timestamp) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_property_timestamp(vertex.inner.pin_mut(), &name, val.millis());
+    }
+
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Timestamp) {
+        let_cxx_string!(name = name);
+        ffi::graphar::edge_add_property_timestamp(edge.inner.pin_mut(), &name, val.millis());
+    }
+}
+
+impl<T> SupportedDataType<Option<T>> for ()
 where
     (): SupportedDataType<T>,
 {
-    fn vertex_add_property(_vertex: &mut VertexBuilder, _name: &str, _val: Vec<T>) {
-        todo!()
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Option<T>) {
+        match val {
+            Some(val) => <() as SupportedDataType<T>>::vertex_add_property(vertex, name, val),
+            None => vertex.add_null(name.into()),
+        }
+    }
+
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Option<T>) {
+        match val {
+            Some(val) => <() as SupportedDataType<T>>::edge_add_property(edge, name, val),
+            None => edge.add_null(name.into()),
+        }
+    }
+}
+
+// GraphAr can only read list columns back through arrays that view raw values,
+// so lists of bit-packed bools, dates and timestamps are left without impls.
+impl SupportedDataType<Vec<i32>> for () {
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Vec<i32>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_property_list_i32(vertex.inner.pin_mut(), &name, &val);
     }
 
-    fn edge_add_property(_edge: &mut EdgeBuilder, _name: &str, _val: Vec<T>) {
-        todo!()
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Vec<i32>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::edge_add_property_list_i32(edge.inner.pin_mut(), &name, &val);
     }
 }
-// TODO(date, timestamp)
+
+impl SupportedDataType<Vec<i64>> for () {
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Vec<i64>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_property_list_i64(vertex.inner.pin_mut(), &name, &val);
+    }
+
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Vec<i64>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::edge_add_property_list_i64(edge.inner.pin_mut(), &name, &val);
+    }
+}
+
+impl SupportedDataType<Vec<f32>> for () {
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Vec<f32>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_property_list_f32(vertex.inner.pin_mut(), &name, &val);
+    }
+
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Vec<f32>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::edge_add_property_list_f32(edge.inner.pin_mut(), &name, &val);
+    }
+}
+
+impl SupportedDataType<Vec<f64>> for () {
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Vec<f64>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_property_list_f64(vertex.inner.pin_mut(), &name, &val);
+    }
+
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Vec<f64>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::edge_add_property_list_f64(edge.inner.pin_mut(), &name, &val);
+    }
+}
+
+impl SupportedDataType<Vec<String>> for () {
+    fn vertex_add_property(vertex: &mut VertexBuilder, name: &str, val: Vec<String>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_property_list_string(vertex.inner.pin_mut(), &name, &val);
+    }
+
+    fn edge_add_property(edge: &mut EdgeBuilder, name: &str, val: Vec<String>) {
+        let_cxx_string!(name = name);
+        ffi::graphar::edge_add_property_list_string(edge.inner.pin_mut(), &name, &val);
+    }
+}
+
+// Multi-valued properties are read back like lists, so the element types
+// match the `Vec<T>` impls above: no bools, dates or timestamps.
+#[diagnostic::on_unimplemented(
+    message = "`{T}` cannot be written as a multi-valued GraphAr property",
+    note = "list and set properties support `i32`, `i64`, `f32`, `f64` and `String` values only"
+)]
+pub trait SupportedMultiDataType<T> {
+    fn vertex_add_multi_property(
+        vertex: &mut VertexBuilder,
+        name: &str,
+        cardinality: Cardinality,
+        val: Vec<T>,
+    );
+}
+
+impl SupportedMultiDataType<i32> for () {
+    fn vertex_add_multi_property(
+        vertex: &mut VertexBuilder,
+        name: &str,
+        cardinality: Cardinality,
+        val: Vec<i32>,
+    ) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_multi_property_i32(
+            vertex.inner.pin_mut(),
+            &name,
+            cardinality,
+            &val,
+        );
+    }
+}
+
+impl SupportedMultiDataType<i64> for () {
+    fn vertex_add_multi_property(
+        vertex: &mut VertexBuilder,
+        name: &str,
+        cardinality: Cardinality,
+        val: Vec<i64>,
+    ) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_multi_property_i64(
+            vertex.inner.pin_mut(),
+            &name,
+            cardinality,
+            &val,
+        );
+    }
+}
+
+impl SupportedMultiDataType<f32> for () {
+    fn vertex_add_multi_property(
+        vertex: &mut VertexBuilder,
+        name: &str,
+        cardinality: Cardinality,
+        val: Vec<f32>,
+    ) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_multi_property_f32(
+            vertex.inner.pin_mut(),
+            &name,
+            cardinality,
+            &val,
+        );
+    }
+}
+
+impl SupportedMultiDataType<f64> for () {
+    fn vertex_add_multi_property(
+        vertex: &mut VertexBuilder,
+        name: &str,
+        cardinality: Cardinality,
+        val: Vec<f64>,
+    ) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_multi_property_f64(
+            vertex.inner.pin_mut(),
+            &name,
+            cardinality,
+            &val,
+        );
+    }
+}
+
+impl SupportedMultiDataType<String> for () {
+    fn vertex_add_multi_property(
+        vertex: &mut VertexBuilder,
+        name: &str,
+        cardinality: Cardinality,
+        val: Vec<String>,
+    ) {
+        let_cxx_string!(name = name);
+        ffi::graphar::vertex_add_multi_property_string(
+            vertex.inner.pin_mut(),
+            &name,
+            cardinality,
+            &val,
+        );
+    }
+}
+
+// Keeps the first occurrence of every value, so sets are written in insertion
+// order. The sets of one vertex are small, so each value is checked against the
+// kept ones. A NaN never equals itself, so all NaNs are matched explicitly as
+// one value; `0.0` and `-0.0` are equal already.
+#[allow(clippy::eq_op)]
+fn dedup<T: PartialEq>(values: Vec<T>) -> Vec<T> {
+    let mut kept: Vec<T> = Vec::with_capacity(values.len());
+    for value in values {
+        let is_nan = value != value;
+        if !kept
+            .iter()
+            .any(|seen| *seen == value || (is_nan && seen != seen))
+        {
+            kept.push(value);
+        }
+    }
+    kept
+}
+
+// Maps every declared property of a vertex type to its cardinality.
+fn cardinalities(groups: Vec<PropertyGroup>) -> HashMap<String, Cardinality> {
+    groups
+        .iter()
+        .flat_map(|group| group.properties())
+        .map(|prop| (prop.name(), prop.cardinality()))
+        .collect()
+}
+
+// Maps every declared property of a vertex or edge type to its nullability.
+fn nullability(groups: Vec<PropertyGroup>) -> HashMap<String, bool> {
+    groups
+        .iter()
+        .flat_map(|group| group.properties())
+        .map(|prop| (prop.name(), prop.is_nullable()))
+        .collect()
+}
+
+fn check_nulls(nullable: &HashMap<String, bool>, nulls: &[String]) -> Result<()> {
+    for name in nulls {
+        match nullable.get(name) {
+            Some(true) => {}
+            Some(false) => {
+                return Err(Error::invalid("property is not nullable").with_property(name));
+            }
+            None => return Err(Error::key("property is not declared").with_property(name)),
+        }
+    }
+    Ok(())
+}
 
 fn vertex_add_property<T, S: AsRef<str>>(vertex: &mut VertexBuilder, name: S, val: T)
 where
@@ -115,8 +365,20 @@ where
     <() as SupportedDataType<T>>::vertex_add_property(vertex, name.as_ref(), val);
 }
 
+// Multi-valued properties are written by `VerticesBuilder::add_vertex`, once
+// the declared cardinality is known.
+type WriteMultiProperty = Box<dyn FnOnce(&mut VertexBuilder, Cardinality)>;
+
+struct MultiProperty {
+    name: String,
+    cardinality: Cardinality,
+    write: WriteMultiProperty,
+}
+
 pub struct VertexBuilder {
     inner: UniquePtr<ffi::graphar::VertexBuilder>,
+    nulls: Vec<String>,
+    multi_properties: Vec<MultiProperty>,
 }
 
 impl Default for VertexBuilder {
@@ -129,19 +391,94 @@ impl VertexBuilder {
     pub fn new() -> Self {
         Self {
             inner: new_vertex(),
+            nulls: Vec::new(),
+            multi_properties: Vec::new(),
         }
     }
 
+    /// Marks `name` as null for this vertex. The property must be declared
+    /// nullable, which is checked by [`VerticesBuilder::add_vertex`].
+    ///
+    /// GraphAr writes null for properties a vertex does not have, so this
+    /// only records the name; it does not remove a value added before.
+    pub fn add_null(&mut self, name: String) {
+        self.nulls.push(name);
+    }
+
     pub fn add_property<T>(&mut self, name: String, property: T)
     where
         (): SupportedDataType<T>,
     {
+        self.nulls.retain(|null| *null != name);
         vertex_add_property(self, &name, property);
     }
+
+    /// Adds all values of a property declared with `Cardinality::List` or
+    /// `Cardinality::Set`. The declared cardinality is checked by
+    /// [`VerticesBuilder::add_vertex`], which drops duplicate values of sets;
+    /// values passed as a list are accepted for a set, not the other way
+    /// around.
+    pub fn add_multi_property<T>(
+        &mut self,
+        name: String,
+        cardinality: Cardinality,
+        values: Vec<T>,
+    ) -> Result<()>
+    where
+        (): SupportedMultiDataType<T>,
+        T: PartialEq + 'static,
+    {
+        if cardinality == Cardinality::Single {
+            return Err(Error::invalid(
+                "property has single cardinality, use `add_property` instead",
+            )
+            .with_property(&name));
+        }
+        self.nulls.retain(|null| *null != name);
+        let write_name = name.clone();
+        self.multi_properties.push(MultiProperty {
+            name,
+            cardinality,
+            write: Box::new(move |vertex, declared| {
+                let values = if declared == Cardinality::Set {
+                    dedup(values)
+                } else {
+                    values
+                };
+                <() as SupportedMultiDataType<T>>::vertex_add_multi_property(
+                    vertex,
+                    &write_name,
+                    declared,
+                    values,
+                );
+            }),
+        });
+        Ok(())
+    }
+}
+
+fn check_cardinality(
+    cardinalities: &HashMap<String, Cardinality>,
+    property: &MultiProperty,
+) -> Result<Cardinality> {
+    let name = &property.name;
+    match (cardinalities.get(name).copied(), property.cardinality) {
+        (None, _) => Err(Error::key("property is not declared").with_property(name)),
+        (Some(Cardinality::Single), _) => {
+            Err(Error::invalid("property is declared with single cardinality").with_property(name))
+        }
+        (Some(Cardinality::List), Cardinality::Set) => {
+            Err(Error::invalid("property is declared with list cardinality").with_property(name))
+        }
+        (Some(declared), _) => Ok(declared),
+    }
 }
 
 pub struct VerticesBuilder {
     inner: SharedPtr<ffi::graphar::VerticesBuilder>,
+    nullable: HashMap<String, bool>,
+    cardinalities: HashMap<String, Cardinality>,
+    path_prefix: PathBuf,
 }
 
 impl VerticesBuilder {
@@ -149,46 +486,73 @@ impl VerticesBuilder {
         vertex_info: &VertexInfo,
         path_prefix: P,
         start_idx: i64,
-    ) -> anyhow::Result<Self> {
-        let prefix_string = path_prefix.as_ref().to_string_lossy().into_owned();
-        let_cxx_string!(prefix = prefix_string);
+    ) -> Result<Self> {
+        let path_prefix = path_prefix.as_ref().to_path_buf();
+        let_cxx_string!(prefix = path_prefix.to_string_lossy().into_owned());
+        let inner = new_vertices_builder(&vertex_info.inner, &prefix, start_idx)
+            .map_err(|err| Error::from(err).with_path(&path_prefix))?;
         Ok(Self {
-            inner: new_vertices_builder(&vertex_info.inner, &prefix, start_idx)?,
+            inner,
+            nullable: nullability(vertex_info.property_groups()),
+            cardinalities: cardinalities(vertex_info.property_groups()),
+            path_prefix,
         })
     }
 
-    pub fn add_vertex(&mut self, mut vertex: VertexBuilder) -> anyhow::Result<()> {
+    pub fn add_vertex(&mut self, mut vertex: VertexBuilder) -> Result<()> {
+        check_nulls(&self.nullable, &vertex.nulls)?;
+        let multi_properties = std::mem::take(&mut vertex.multi_properties);
+        let declared = multi_properties
+            .iter()
+            .map(|property| check_cardinality(&self.cardinalities, property))
+            .collect::<Result<Vec<_>>>()?;
+        for (property, declared) in multi_properties.into_iter().zip(declared) {
+            (property.write)(&mut vertex, declared);
+        }
         unsafe { add_vertex(self.inner.pin_mut_unchecked(), vertex.inner.pin_mut())? };
         Ok(())
     }
 
-    pub fn dump(&mut self) -> anyhow::Result<()> {
-        unsafe { vertices_dump(self.inner.pin_mut_unchecked())? };
+    pub fn dump(&mut self) -> Result<()> {
+        unsafe { vertices_dump(self.inner.pin_mut_unchecked()) }
+            .map_err(|err| Error::from(err).with_path(&self.path_prefix))?;
         Ok(())
     }
 }
 
 pub struct EdgeBuilder {
     inner: UniquePtr<ffi::graphar::EdgeBuilder>,
+    nulls: Vec<String>,
 }
 
 impl EdgeBuilder {
     pub fn new(src_id: i64, dst_id: i64) -> Self {
         Self {
             inner: new_edge(src_id, dst_id),
+            nulls: Vec::new(),
         }
     }
 
+    /// Marks `name` as null for this edge, like [`VertexBuilder::add_null`].
+    /// The property must be declared nullable, which is checked by
+    /// [`EdgesBuilder::add_edge`].
+    pub fn add_null(&mut self, name: String) {
+        self.nulls.push(name);
+    }
+
     pub fn add_property<T>(&mut self, name: String, property: T)
     where
         (): SupportedDataType<T>,
     {
+        self.nulls.retain(|null| *null != name);
         <() as SupportedDataType<T>>::edge_add_property(self, &name, property);
     }
 }
 
 pub struct EdgesBuilder {
     inner: SharedPtr<ffi::graphar::EdgesBuilder>,
+    nullable: HashMap<String, bool>,
+    path_prefix: PathBuf,
 }
 
 impl EdgesBuilder {
@@ -197,20 +561,27 @@ impl EdgesBuilder {
         path_prefix: P,
         adj_list_type: AdjListType,
         vertices_num: i64,
-    ) -> anyhow::Result<Self> {
-        let prefix_string = path_prefix.as_ref().to_string_lossy().into_owned();
-        let_cxx_string!(prefix = prefix_string);
-        let inner = new_edges_builder(&edge_info.inner, &prefix, adj_list_type, vertices_num)?;
-        Ok(Self { inner })
+    ) -> Result<Self> {
+        let path_prefix = path_prefix.as_ref().to_path_buf();
+        let_cxx_string!(prefix = path_prefix.to_string_lossy().into_owned());
+        let inner = new_edges_builder(&edge_info.inner, &prefix, adj_list_type, vertices_num)
+            .map_err(|err| Error::from(err).with_path(&path_prefix))?;
+        Ok(Self {
+            inner,
+            nullable: nullability(edge_info.property_groups()),
+            path_prefix,
+        })
     }
 
-    pub fn add_edge(&mut self, mut edge: EdgeBuilder) -> anyhow::Result<()> {
+    pub fn add_edge(&mut self, mut edge: EdgeBuilder) -> Result<()> {
+        check_nulls(&self.nullable, &edge.nulls)?;
         unsafe { ffi::graphar::add_edge(self.inner.pin_mut_unchecked(), edge.inner.pin_mut())? };
         Ok(())
     }
 
-    pub fn dump(&mut self) -> anyhow::Result<()> {
-        unsafe { edges_dump(self.inner.pin_mut_unchecked())? };
+    pub fn dump(&mut self) -> Result<()> {
+        unsafe { edges_dump(self.inner.pin_mut_unchecked()) }
+            .map_err(|err| Error::from(err).with_path(&self.path_prefix))?;
         Ok(())
     }
 }
@@ -238,9 +609,9 @@ mod tests {
             Cardinality::Single,
         ));
         let mut pgv = PropertyGroupVector::new();
-        pgv.add_property_group(PropertyGroup::new(props, FileType::Parquet, ""));
+        pgv.add_property_group(PropertyGroup::new(props, FileType::Parquet, "").unwrap());
         let ver = InfoVersion::new(1).unwrap();
-        VertexInfo::new("person".into(), 4, pgv, vec![], "", ver)
+        VertexInfo::new("person".into(), 4, pgv, vec![], "", ver).unwrap()
     }
 
     fn make_edge_info() -> EdgeInfo {
@@ -259,11 +630,8 @@ mod tests {
             Cardinality::Single,
         ));
         let mut edge_prop_groups = PropertyGroupVector::new();
-        edge_prop_groups.add_property_group(PropertyGroup::new(
-            edge_props,
-            FileType::Csv,
-            "props/",
-        ));
+        edge_prop_groups
+            .add_property_group(PropertyGroup::new(edge_props, FileType::Csv, "props/").unwrap());
         let ver = InfoVersion::new(1).unwrap();
         EdgeInfo::new(
             "person",
@@ -278,6 +646,7 @@ mod tests {
             "",
             ver,
         )
+        .unwrap()
     }
 
     #[test]
@@ -364,9 +733,9 @@ mod tests {
             Cardinality::Single,
         ));
         let mut pgv = PropertyGroupVector::new();
-        pgv.add_property_group(PropertyGroup::new(props, FileType::Parquet, ""));
+        pgv.add_property_group(PropertyGroup::new(props, FileType::Parquet, "").unwrap());
         let ver = InfoVersion::new(1).unwrap();
-        let vi = VertexInfo::new("person".into(), 4, pgv, vec![], "", ver);
+        let vi = VertexInfo::new("person".into(), 4, pgv, vec![], "", ver).unwrap();
 
         let tmp = tempdir().unwrap();
         let mut vb = VerticesBuilder::new(&vi, tmp.path().join("vertex/"), 0).unwrap();
@@ -381,4 +750,150 @@ mod tests {
         vb.add_vertex(alice).unwrap();
         vb.dump().unwrap();
     }
+
+    #[test]
+    fn test_builders_list_property_types() {
+        let mut props = PropertyVec::new();
+        props.add_property(Property::new(
+            "id",
+            &DataType::int64(),
+            true,
+            false,
+            Cardinality::Single,
+        ));
+        props.add_property(Property::new(
+            "scores",
+            &DataType::list(&DataType::float64()),
+            false,
+            false,
+            Cardinality::Single,
+        ));
+        props.add_property(Property::new(
+            "tags",
+            &DataType::list(&DataType::string()),
+            false,
+            false,
+            Cardinality::Single,
+        ));
+        let mut pgv = PropertyGroupVector::new();
+        pgv.add_property_group(PropertyGroup::new(props, FileType::Parquet, "").unwrap());
+        let ver = InfoVersion::new(1).unwrap();
+        let vi = VertexInfo::new("person".into(), 4, pgv, vec![], "", ver).unwrap();
+
+        let tmp = tempdir().unwrap();
+        let mut vb = VerticesBuilder::new(&vi, tmp.path().join("vertex/"), 0).unwrap();
+
+        let mut alice = VertexBuilder::new();
+        alice.add_property("id".into(), 1_i64);
+        alice.add_property("scores".into(), vec![0.5_f64, 1.5]);
+        alice.add_property("tags".into(), vec!["a".to_string(), "b".to_string()]);
+        vb.add_vertex(alice).unwrap();
+        vb.dump().unwrap();
+    }
+
+    #[test]
+    fn test_vertex_builder_multi_property() {
+        let mut props = PropertyVec::new();
+        props.add_property(Property::new(
+            "id",
+            &DataType::int64(),
+            true,
+            false,
+            Cardinality::Single,
+        ));
+        props.add_property(Property::new(
+            "interests",
+            &DataType::string(),
+            false,
+            false,
+            Cardinality::Set,
+        ));
+        let mut pgv = PropertyGroupVector::new();
+        pgv.add_property_group(PropertyGroup::new(props, FileType::Parquet, "").unwrap());
+        let ver = InfoVersion::new(1).unwrap();
+        let vi = VertexInfo::new("person".into(), 4, pgv, vec![], "", ver).unwrap();
+
+        let tmp = tempdir().unwrap();
+        let mut vb = VerticesBuilder::new(&vi, tmp.path().join("vertex/"), 0).unwrap();
+
+        let mut alice = VertexBuilder::new();
+        alice.add_property("id".into(), 1_i64);
+        alice
+            .add_multi_property(
+                "interests".into(),
+                Cardinality::Set,
+                vec!["go".to_string(), "rust".to_string(), "go".to_string()],
+            )
+            .unwrap();
+        assert!(
+            alice
+                .add_multi_property("id".into(), Cardinality::Single, vec![2_i64])
+                .is_err()
+        );
+        vb.add_vertex(alice).unwrap();
+
+        // Declared cardinalities are enforced when the vertex is added.
+        let mut bob = VertexBuilder::new();
+        bob.add_property("id".into(), 2_i64);
+        bob.add_multi_property("id".into(), Cardinality::List, vec![2_i64])
+            .unwrap();
+        let err = vb.add_vertex(bob).unwrap_err();
+        assert!(matches!(err, Error::Invalid(_)));
+        assert_eq!(err.property(), Some("id"));
+
+        let mut carol = VertexBuilder::new();
+        carol.add_property("id".into(), 3_i64);
+        carol
+            .add_multi_property("hobbies".into(), Cardinality::Set, vec![1_i32])
+            .unwrap();
+        assert!(matches!(vb.add_vertex(carol), Err(Error::Key(_))));
+        vb.dump().unwrap();
+
+        assert_eq!(dedup(vec![3_i64, 1, 3, 2, 1]), vec![3, 1, 2]);
+        let floats = dedup(vec![f64::NAN, 0.0, f64::NAN, -0.0, 1.5]);
+        assert_eq!(floats.len(), 3);
+        assert!(floats[0].is_nan());
+        assert_eq!(floats[1..], [0.0, 1.5]);
+    }
+
+    #[test]
+    fn test_builders_nulls() {
+        let vi = make_vertex_info();
+        let tmp = tempdir().unwrap();
+        let mut vb = VerticesBuilder::new(&vi, tmp.path().join("vertex/"), 0).unwrap();
+
+        let mut alice = VertexBuilder::new();
+        alice.add_property("id".into(), 1_i64);
+        alice.add_null("name".into());
+        assert!(vb.add_vertex(alice).is_err());
+
+        // A value set after a null replaces it
+        let mut carol = VertexBuilder::new();
+        carol.add_property("id".into(), 3_i64);
+        carol.add_null("name".into());
+        carol.add_property("name".into(), "carol".to_string());
+        vb.add_vertex(carol).unwrap();
+
+        let mut bob = VertexBuilder::new();
+        bob.add_property("id".into(), 2_i64);
+        bob.add_property::<Option<String>>("nickname".into(), None);
+        assert!(vb.add_vertex(bob).is_err());
+
+        let ei = make_edge_info();
+        let mut eb = EdgesBuilder::new(
+            &ei,
+            tmp.path().join("edge/"),
+            AdjListType::OrderedBySource,
+            2,
+        )
+        .unwrap();
+
+        let mut e = EdgeBuilder::new(0, 1);
+        e.add_property::<Option<String>>("friend".into(), None);
+        eb.add_edge(e).unwrap();
+        let mut e = EdgeBuilder::new(1, 0);
+        e.add_property("friend".into(), Some("alice".to_string()));
+        eb.add_edge(e).unwrap();
+        eb.dump().unwrap();
+    }
 }
@@ -1,15 +1,18 @@
-// Currently do not support cardinality
-
 pub use ffi::graphar::{AdjListType, Cardinality, FileType, Type};
 
+mod validate;
+
+pub use validate::{FileKind, Issue, ValidationReport};
+
 use crate::{
-    cxx_string_to_string,
+    Error, Result, cxx_string_to_string,
     ffi::{self, SharedPropertyGroup, SharedVertexInfo, graphar::*},
 };
 use cxx::{CxxVector, SharedPtr, UniquePtr, let_cxx_string};
 use std::{
+    collections::BTreeMap,
     fmt::{Debug, Display},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 #[derive(Clone)]
@@ -54,10 +57,10 @@ impl Debug for DataType {
 }
 
 impl DataType {
-    pub fn value_type(&self) -> Self {
-        DataType {
-            inner: self.inner.value_type().clone(),
-        }
+    /// The element type of a list type, `None` for other types.
+    pub fn value_type(&self) -> Option<Self> {
+        let inner = non_null(self.inner.value_type().clone())?;
+        Some(DataType { inner })
     }
 
     pub fn id(&self) -> Type {
@@ -120,7 +123,7 @@ impl DataType {
 }
 
 pub struct Property {
-    inner: UniquePtr<ffi::graphar::Property>,
+    pub(crate) inner: UniquePtr<ffi::graphar::Property>,
 }
 
 impl Property {
@@ -152,6 +155,48 @@ impl Property {
         let ty = property_get_type(&self.inner);
         DataType { inner: ty.clone() }
     }
+
+    pub fn is_primary(&self) -> bool {
+        property_is_primary(&self.inner)
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        property_is_nullable(&self.inner)
+    }
+
+    pub fn cardinality(&self) -> Cardinality {
+        property_get_cardinality(&self.inner)
+    }
+}
+
+impl Clone for Property {
+    fn clone(&self) -> Self {
+        Self {
+            inner: property_clone(&self.inner),
+        }
+    }
+}
+
+impl PartialEq for Property {
+    fn eq(&self, other: &Self) -> bool {
+        self.name() == other.name()
+            && self.data_type() == other.data_type()
+            && self.is_primary() == other.is_primary()
+            && self.is_nullable() == other.is_nullable()
+            && self.cardinality() == other.cardinality()
+    }
+}
+
+impl Debug for Property {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Property")
+            .field("name", &self.name())
+            .field("data_type", &self.data_type())
+            .field("is_primary", &self.is_primary())
+            .field("is_nullable", &self.is_nullable())
+            .field("cardinality", &self.cardinality())
+            .finish()
+    }
 }
 
 // TODO(how to design this)
@@ -177,20 +222,43 @@ impl PropertyVec {
     }
 }
 
+// GraphAr returns null for missing lookups and for rejected constructor
+// arguments.
+fn non_null<T>(ptr: SharedPtr<T>) -> Option<SharedPtr<T>>
+where
+    T: cxx::memory::SharedPtrTarget,
+{
+    (!ptr.is_null()).then_some(ptr)
+}
+
+fn index_from_cxx(index: i32) -> Option<usize> {
+    usize::try_from(index).ok()
+}
+
+fn index_to_cxx(index: usize) -> Option<i32> {
+    i32::try_from(index).ok()
+}
+
 pub struct PropertyGroup {
-    inner: SharedPtr<ffi::graphar::PropertyGroup>,
+    pub(crate) inner: SharedPtr<ffi::graphar::PropertyGroup>,
 }
 
 impl PropertyGroup {
-    pub fn new<P: AsRef<Path>>(properties: PropertyVec, file_type: FileType, prefix: P) -> Self {
+    /// Returns [`Error::Invalid`] if `properties` is empty.
+    pub fn new<P: AsRef<Path>>(
+        properties: PropertyVec,
+        file_type: FileType,
+        prefix: P,
+    ) -> Result<Self> {
         let prefix_string = prefix.as_ref().to_string_lossy().into_owned();
         let_cxx_string!(prefix = prefix_string);
         let properties_vec = properties.inner;
         let props = properties_vec
             .as_ref()
             .expect("properties vec should be valid");
-        let inner = CreatePropertyGroup(props, file_type, &prefix);
-        Self { inner }
+        let inner = non_null(CreatePropertyGroup(props, file_type, &prefix))
+            .ok_or_else(|| Error::invalid("a property group needs at least one property"))?;
+        Ok(Self { inner })
     }
 
     pub fn properties(&self) -> Vec<Property> {
@@ -247,7 +315,7 @@ impl Clone for InfoVersion {
 }
 
 impl InfoVersion {
-    pub fn new(version: i32) -> anyhow::Result<Self> {
+    pub fn new(version: i32) -> Result<Self> {
         Ok(Self {
             inner: new_const_info_version(version)?,
         })
@@ -260,6 +328,8 @@ pub struct VertexInfo {
 
 impl VertexInfo {
     // TODO(use &str for prefix)
+    /// Returns [`Error::Invalid`] if `type` is empty or `chunk_size` is not
+    /// positive.
     pub fn new<P: AsRef<Path>>(
         r#type: String,
         chunk_size: i64,
@@ -267,7 +337,7 @@ impl VertexInfo {
         labels: Vec<String>,
         prefix: P,
         version: InfoVersion,
-    ) -> Self {
+    ) -> Result<Self> {
         let inner = {
             let groups = property_groups.inner;
             let groups_ref = groups.as_ref().expect("property group vec should be valid");
@@ -281,7 +351,10 @@ impl VertexInfo {
                 version.inner,
             )
         };
-        Self { inner }
+        let inner = non_null(inner).ok_or_else(|| {
+            Error::invalid("a vertex info needs a non-empty type and a positive chunk size")
+        })?;
+        Ok(Self { inner })
     }
 
     pub fn ty(&self) -> String {
@@ -296,10 +369,9 @@ impl VertexInfo {
         cxx_string_to_string(self.inner.GetPrefix())
     }
 
-    pub fn version(&self) -> InfoVersion {
-        InfoVersion {
-            inner: self.inner.version().clone(),
-        }
+    pub fn version(&self) -> Option<InfoVersion> {
+        let inner = non_null(self.inner.version().clone())?;
+        Some(InfoVersion { inner })
     }
 
     pub fn labels(&self) -> Vec<String> {
@@ -328,36 +400,61 @@ impl VertexInfo {
         pgs
     }
 
-    pub fn property_group<S: AsRef<str>>(&self, property_name: S) -> PropertyGroup {
+    /// The property group holding `property_name`.
+    pub fn property_group<S: AsRef<str>>(&self, property_name: S) -> Option<PropertyGroup> {
         let_cxx_string!(name = property_name.as_ref());
-        PropertyGroup {
-            inner: self.inner.GetPropertyGroup(&name),
-        }
+        let inner = non_null(self.inner.GetPropertyGroup(&name))?;
+        Some(PropertyGroup { inner })
     }
 
-    pub fn property_group_by_index(&self, index: i32) -> PropertyGroup {
-        PropertyGroup {
-            inner: self.inner.GetPropertyGroupByIndex(index),
-        }
+    pub fn property_group_by_index(&self, index: usize) -> Option<PropertyGroup> {
+        let inner = non_null(self.inner.GetPropertyGroupByIndex(index_to_cxx(index)?))?;
+        Some(PropertyGroup { inner })
+    }
+
+    /// Returns a copy of this vertex type with `property_group` added. Fails
+    /// if one of its properties already exists.
+    pub fn add_property_group(&self, property_group: PropertyGroup) -> Result<VertexInfo> {
+        Ok(Self {
+            inner: vertex_info_add_property_group(&self.inner, &property_group.inner)?,
+        })
+    }
+
+    // The `*_file_path` methods return paths relative to the graph prefix.
+
+    /// The file of chunk `chunk_index` of `property_group`.
+    pub fn property_file_path(
+        &self,
+        property_group: &PropertyGroup,
+        chunk_index: i64,
+    ) -> Result<PathBuf> {
+        Ok(vertex_info_file_path(&self.inner, &property_group.inner, chunk_index)?.into())
+    }
+
+    /// The file holding the number of vertices.
+    pub fn vertices_num_file_path(&self) -> Result<PathBuf> {
+        Ok(vertex_info_vertices_num_file_path(&self.inner)?.into())
     }
 
-    pub fn save<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         let path_string = path.as_ref().to_string_lossy().into_owned();
-        let_cxx_string!(path = path_string);
-        vertex_info_save(&self.inner, &path)?;
+        let_cxx_string!(p = path_string);
+        vertex_info_save(&self.inner, &p).map_err(|err| Error::from(err).with_path(&path))?;
         Ok(())
     }
 
-    pub fn dump(&self) -> anyhow::Result<String> {
+    pub fn dump(&self) -> Result<String> {
         Ok(vertex_info_dump(&self.inner).map(|inner| inner.to_string())?)
     }
 }
 
+#[derive(Clone)]
 pub struct GraphInfo {
     pub(crate) inner: SharedPtr<ffi::graphar::GraphInfo>,
 }
 
 impl GraphInfo {
+    /// Returns [`Error::Invalid`] if `name` is empty.
     pub fn new<S: AsRef<str>, P: AsRef<Path>>(
         name: S,
         vertex_infos: &Vec<VertexInfo>,
@@ -365,8 +462,7 @@ impl GraphInfo {
         labels: &Vec<String>,
         prefix: P,
         version: Option<InfoVersion>,
-        // TODO(extra_info)
-    ) -> Self {
+    ) -> Result<Self> {
         let_cxx_string!(name = name.as_ref());
         let_cxx_string!(prefix = prefix.as_ref().to_string_lossy().into_owned());
 
@@ -392,22 +488,23 @@ impl GraphInfo {
             SharedPtr::null()
         };
 
-        Self {
-            inner: create_graph_info(
-                &name,
-                v_infos.as_ref().expect("vertex_infos is null"),
-                e_infos.as_ref().expect("edge_infos is null"),
-                labels,
-                &prefix,
-                version,
-            ),
-        }
+        let inner = create_graph_info(
+            &name,
+            v_infos.as_ref().expect("vertex_infos is null"),
+            e_infos.as_ref().expect("edge_infos is null"),
+            labels,
+            &prefix,
+            version,
+        );
+        let inner =
+            non_null(inner).ok_or_else(|| Error::invalid("a graph info needs a non-empty name"))?;
+        Ok(Self { inner })
     }
 
-    pub fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
         let path_string = path.as_ref().to_string_lossy().into_owned();
         let_cxx_string!(p = path_string);
-        let inner = load_graph_info(&p)?;
+        let inner = load_graph_info(&p).map_err(|err| Error::from(err).with_path(&path))?;
         Ok(Self { inner })
     }
 
@@ -428,40 +525,51 @@ impl GraphInfo {
         cxx_string_to_string(self.inner.GetPrefix())
     }
 
-    pub fn version(&self) -> InfoVersion {
-        let sp = self.inner.version();
-        InfoVersion { inner: sp.clone() }
+    /// `None` if the graph info was created or loaded without a version.
+    pub fn version(&self) -> Option<InfoVersion> {
+        let inner = non_null(self.inner.version().clone())?;
+        Some(InfoVersion { inner })
     }
 
-    pub fn vertex_info<S: AsRef<str>>(&self, r#type: S) -> VertexInfo {
+    pub fn vertex_info<S: AsRef<str>>(&self, r#type: S) -> Option<VertexInfo> {
         let_cxx_string!(ty = r#type.as_ref());
-        VertexInfo {
-            inner: self.inner.GetVertexInfo(&ty),
-        }
+        let inner = non_null(self.inner.GetVertexInfo(&ty))?;
+        Some(VertexInfo { inner })
     }
 
-    pub fn edge_info<S: AsRef<str>>(&self, src_type: S, edge_type: S, dst_type: S) -> EdgeInfo {
+    pub fn edge_info<S: AsRef<str>>(
+        &self,
+        src_type: S,
+        edge_type: S,
+        dst_type: S,
+    ) -> Option<EdgeInfo> {
         let_cxx_string!(src_type = src_type.as_ref());
         let_cxx_string!(edge_type = edge_type.as_ref());
         let_cxx_string!(dst_type = dst_type.as_ref());
 
-        EdgeInfo {
-            inner: self.inner.GetEdgeInfo(&src_type, &edge_type, &dst_type),
-        }
+        let inner = non_null(self.inner.GetEdgeInfo(&src_type, &edge_type, &dst_type))?;
+        Some(EdgeInfo { inner })
     }
 
-    pub fn vertex_info_index<S: AsRef<str>>(&self, r#type: S) -> i32 {
+    pub fn vertex_info_index<S: AsRef<str>>(&self, r#type: S) -> Option<usize> {
         let_cxx_string!(ty = r#type.as_ref());
-        self.inner.GetVertexInfoIndex(&ty)
+        index_from_cxx(self.inner.GetVertexInfoIndex(&ty))
     }
 
-    pub fn edge_info_index<S: AsRef<str>>(&self, src_type: S, edge_type: S, dst_type: S) -> i32 {
+    pub fn edge_info_index<S: AsRef<str>>(
+        &self,
+        src_type: S,
+        edge_type: S,
+        dst_type: S,
+    ) -> Option<usize> {
         let_cxx_string!(src_type = src_type.as_ref());
         let_cxx_string!(edge_type = edge_type.as_ref());
         let_cxx_string!(dst_type = dst_type.as_ref());
 
-        self.inner
-            .GetEdgeInfoIndex(&src_type, &edge_type, &dst_type)
+        index_from_cxx(
+            self.inner
+                .GetEdgeInfoIndex(&src_type, &edge_type, &dst_type),
+        )
     }
 
     pub fn vertex_infos(&self) -> Vec<VertexInfo> {
@@ -496,14 +604,50 @@ impl GraphInfo {
         self.inner.EdgeInfoNum()
     }
 
-    pub fn save<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+    /// Free-form key/value metadata, stored under `extra_info` in the YAML.
+    pub fn extra_info(&self) -> BTreeMap<String, String> {
+        graph_info_extra_info(&self.inner)
+            .chunks_exact(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect()
+    }
+
+    /// Returns a copy of this graph with its extra info replaced by
+    /// `extra_info`.
+    pub fn with_extra_info(&self, extra_info: BTreeMap<String, String>) -> GraphInfo {
+        let flat = extra_info
+            .into_iter()
+            .flat_map(|(key, value)| [key, value])
+            .collect();
+        Self {
+            inner: graph_info_with_extra_info(&self.inner, &flat),
+        }
+    }
+
+    /// Returns a copy of this graph with `vertex_info` added. Fails if the
+    /// vertex type already exists.
+    pub fn add_vertex(&self, vertex_info: VertexInfo) -> Result<GraphInfo> {
+        Ok(Self {
+            inner: graph_info_add_vertex(&self.inner, &vertex_info.inner)?,
+        })
+    }
+
+    /// Returns a copy of this graph with `edge_info` added. Fails if the edge
+    /// type already exists.
+    pub fn add_edge(&self, edge_info: EdgeInfo) -> Result<GraphInfo> {
+        Ok(Self {
+            inner: graph_info_add_edge(&self.inner, &edge_info.inner)?,
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         let path_string = path.as_ref().to_string_lossy().into_owned();
         let_cxx_string!(p = path_string);
-        graph_info_save(&self.inner, &p)?;
+        graph_info_save(&self.inner, &p).map_err(|err| Error::from(err).with_path(&path))?;
         Ok(())
     }
 
-    pub fn dump(&self) -> anyhow::Result<String> {
+    pub fn dump(&self) -> Result<String> {
         Ok(graph_info_dump(&self.inner).map(|u| u.to_string())?)
     }
 }
@@ -561,6 +705,8 @@ pub struct EdgeInfo {
 }
 
 impl EdgeInfo {
+    /// Returns [`Error::Invalid`] if a type is empty, a chunk size is not
+    /// positive or `adjacent_lists` is empty.
     #[allow(clippy::too_many_arguments)]
     pub fn new<P: AsRef<Path>>(
         src_type: &str,
@@ -574,7 +720,7 @@ impl EdgeInfo {
         property_groups: PropertyGroupVector,
         path_prefix: P,
         version: InfoVersion,
-    ) -> Self {
+    ) -> Result<Self> {
         let_cxx_string!(src = src_type);
         let_cxx_string!(edge = edge_type);
         let_cxx_string!(dst = dst_type);
@@ -601,7 +747,12 @@ impl EdgeInfo {
             &prefix,
             version.inner,
         );
-        Self { inner }
+        let inner = non_null(inner).ok_or_else(|| {
+            Error::invalid(
+                "an edge info needs non-empty types, positive chunk sizes and an adjacency list",
+            )
+        })?;
+        Ok(Self { inner })
     }
 
     pub fn src_type(&self) -> String {
@@ -636,20 +787,18 @@ impl EdgeInfo {
         self.inner.IsDirected()
     }
 
-    pub fn version(&self) -> InfoVersion {
-        InfoVersion {
-            inner: self.inner.version().clone(),
-        }
+    pub fn version(&self) -> Option<InfoVersion> {
+        let inner = non_null(self.inner.version().clone())?;
+        Some(InfoVersion { inner })
     }
 
     pub fn has_adjacent_list_type(&self, adj_list_type: AdjListType) -> bool {
         self.inner.HasAdjacentListType(adj_list_type)
     }
 
-    pub fn adjacent_list(&self, adj_list_type: AdjListType) -> AdjacentList {
-        AdjacentList {
-            inner: self.inner.GetAdjacentList(adj_list_type),
-        }
+    pub fn adjacent_list(&self, adj_list_type: AdjListType) -> Option<AdjacentList> {
+        let inner = non_null(self.inner.GetAdjacentList(adj_list_type))?;
+        Some(AdjacentList { inner })
     }
 
     pub fn property_group_num(&self) -> i32 {
@@ -668,35 +817,116 @@ impl EdgeInfo {
         pgs
     }
 
-    pub fn property_group<S: AsRef<str>>(&self, property_name: S) -> PropertyGroup {
+    /// The property group holding `property_name`.
+    pub fn property_group<S: AsRef<str>>(&self, property_name: S) -> Option<PropertyGroup> {
         let_cxx_string!(name = property_name.as_ref());
-        PropertyGroup {
-            inner: self.inner.GetPropertyGroup(&name),
-        }
+        let inner = non_null(self.inner.GetPropertyGroup(&name))?;
+        Some(PropertyGroup { inner })
     }
 
-    pub fn property_group_by_index(&self, index: i32) -> PropertyGroup {
-        PropertyGroup {
-            inner: self.inner.GetPropertyGroupByIndex(index),
-        }
+    pub fn property_group_by_index(&self, index: usize) -> Option<PropertyGroup> {
+        let inner = non_null(self.inner.GetPropertyGroupByIndex(index_to_cxx(index)?))?;
+        Some(PropertyGroup { inner })
+    }
+
+    /// Returns a copy of this edge type with `property_group` added. Fails if
+    /// one of its properties already exists.
+    pub fn add_property_group(&self, property_group: PropertyGroup) -> Result<EdgeInfo> {
+        Ok(Self {
+            inner: edge_info_add_property_group(&self.inner, &property_group.inner)?,
+        })
+    }
+
+    /// Returns a copy of this edge type with `adj_list` added. Fails if an
+    /// adjacency list of the same type already exists.
+    pub fn add_adjacent_list(&self, adj_list: AdjacentList) -> Result<EdgeInfo> {
+        Ok(Self {
+            inner: edge_info_add_adjacent_list(&self.inner, &adj_list.inner)?,
+        })
     }
 
-    pub fn save<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+    // The `*_file_path` methods return paths relative to the graph prefix and
+    // fail if the edge type has no adjacency list of `adj_list_type`.
+
+    /// The file holding the number of source (or, for lists by destination,
+    /// destination) vertices of an adjacency list.
+    pub fn vertices_num_file_path(&self, adj_list_type: AdjListType) -> Result<PathBuf> {
+        Ok(edge_info_vertices_num_file_path(&self.inner, adj_list_type)?.into())
+    }
+
+    /// The file holding the number of edges of vertex chunk
+    /// `vertex_chunk_index`.
+    pub fn edges_num_file_path(
+        &self,
+        vertex_chunk_index: i64,
+        adj_list_type: AdjListType,
+    ) -> Result<PathBuf> {
+        Ok(edge_info_edges_num_file_path(&self.inner, vertex_chunk_index, adj_list_type)?.into())
+    }
+
+    /// The file of edge chunk `edge_chunk_index` of vertex chunk
+    /// `vertex_chunk_index`.
+    pub fn adj_list_file_path(
+        &self,
+        vertex_chunk_index: i64,
+        edge_chunk_index: i64,
+        adj_list_type: AdjListType,
+    ) -> Result<PathBuf> {
+        Ok(edge_info_adj_list_file_path(
+            &self.inner,
+            vertex_chunk_index,
+            edge_chunk_index,
+            adj_list_type,
+        )?
+        .into())
+    }
+
+    /// The offset file of vertex chunk `vertex_chunk_index`, for ordered
+    /// adjacency lists.
+    pub fn adj_list_offset_file_path(
+        &self,
+        vertex_chunk_index: i64,
+        adj_list_type: AdjListType,
+    ) -> Result<PathBuf> {
+        Ok(
+            edge_info_adj_list_offset_file_path(&self.inner, vertex_chunk_index, adj_list_type)?
+                .into(),
+        )
+    }
+
+    /// The file of `property_group` for the edges in
+    /// [`EdgeInfo::adj_list_file_path`].
+    pub fn property_file_path(
+        &self,
+        property_group: &PropertyGroup,
+        adj_list_type: AdjListType,
+        vertex_chunk_index: i64,
+        edge_chunk_index: i64,
+    ) -> Result<PathBuf> {
+        Ok(edge_info_property_file_path(
+            &self.inner,
+            &property_group.inner,
+            adj_list_type,
+            vertex_chunk_index,
+            edge_chunk_index,
+        )?
+        .into())
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         let path_string = path.as_ref().to_string_lossy().into_owned();
         let_cxx_string!(p = path_string);
-        edge_info_save(&self.inner, &p)?;
+        edge_info_save(&self.inner, &p).map_err(|err| Error::from(err).with_path(&path))?;
         Ok(())
     }
 
-    pub fn dump(&self) -> anyhow::Result<String> {
+    pub fn dump(&self) -> Result<String> {
         Ok(edge_info_dump(&self.inner).map(|u| u.to_string())?)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::iter;
-
     use tempfile::tempdir;
 
     use super::*;
@@ -759,7 +989,7 @@ mod tests {
         let nested_list = DataType::list(&list_of_int32);
         assert_eq!(format!("{}", nested_list), "list<list<int32>>");
 
-        assert_eq!(format!("{}", int32.value_type()), "null");
+        assert_eq!(format!("{}", DataType::null()), "null");
     }
 
     #[test]
@@ -794,46 +1024,46 @@ mod tests {
         let nested_list = DataType::list(&list_of_int32);
         assert_eq!(format!("{:?}", nested_list), "list<list<int32>>");
 
-        assert_eq!(format!("{:?}", int32.value_type()), "null");
+        assert_eq!(format!("{:?}", DataType::null()), "null");
     }
 
     #[test]
     fn test_data_type_value_type() {
         let bool_type = DataType::bool();
-        assert_eq!(bool_type.value_type(), DataType::null());
+        assert_eq!(bool_type.value_type(), None);
 
         let int32 = DataType::int32();
-        assert_eq!(int32.value_type(), DataType::null());
+        assert_eq!(int32.value_type(), None);
 
         let int64 = DataType::int64();
-        assert_eq!(int64.value_type(), DataType::null());
+        assert_eq!(int64.value_type(), None);
 
         let float32 = DataType::float32();
-        assert_eq!(float32.value_type(), DataType::null());
+        assert_eq!(float32.value_type(), None);
 
         let float64 = DataType::float64();
-        assert_eq!(float64.value_type(), DataType::null());
+        assert_eq!(float64.value_type(), None);
 
         let string = DataType::string();
-        assert_eq!(string.value_type(), DataType::null());
+        assert_eq!(string.value_type(), None);
 
         let date = DataType::date();
-        assert_eq!(date.value_type(), DataType::null());
+        assert_eq!(date.value_type(), None);
 
         let timestamp = DataType::timestamp();
-        assert_eq!(timestamp.value_type(), DataType::null());
+        assert_eq!(timestamp.value_type(), None);
 
         let list_of_int32 = DataType::list(&int32);
-        assert_eq!(list_of_int32.value_type(), int32);
+        assert_eq!(list_of_int32.value_type(), Some(int32));
 
         let list_of_float32 = DataType::list(&float32);
-        assert_eq!(list_of_float32.value_type(), float32);
+        assert_eq!(list_of_float32.value_type(), Some(float32));
 
         let list_of_string = DataType::list(&string);
-        assert_eq!(list_of_string.value_type(), string);
+        assert_eq!(list_of_string.value_type(), Some(string));
 
         let nested_list = DataType::list(&list_of_int32);
-        assert_eq!(nested_list.value_type(), list_of_int32);
+        assert_eq!(nested_list.value_type(), Some(list_of_int32));
     }
 
     #[test]
@@ -864,11 +1094,36 @@ mod tests {
 
         let list_of_int32 = DataType::list(&int32);
         assert_eq!(list_of_int32.id(), Type::List);
-        assert_eq!(list_of_int32.value_type().id(), Type::Int32);
+        assert_eq!(list_of_int32.value_type().unwrap().id(), Type::Int32);
 
         let list_of_lists = DataType::list(&list_of_int32);
         assert_eq!(list_of_lists.id(), Type::List);
-        assert_eq!(list_of_lists.value_type().id(), Type::List);
+        assert_eq!(list_of_lists.value_type().unwrap().id(), Type::List);
+    }
+
+    #[test]
+    fn test_property_metadata() {
+        let id = Property::new("id", &DataType::int64(), true, false, Cardinality::Single);
+        assert_eq!(id.name(), "id");
+        assert!(id.is_primary());
+        assert!(!id.is_nullable());
+        assert_eq!(id.cardinality(), Cardinality::Single);
+
+        let tags = Property::new("tags", &DataType::string(), false, true, Cardinality::Set);
+        assert!(!tags.is_primary());
+        assert!(tags.is_nullable());
+        assert_eq!(tags.cardinality(), Cardinality::Set);
+
+        let cloned = tags.clone();
+        assert_eq!(cloned, tags);
+        assert_ne!(cloned, id);
+        assert!(format!("{tags:?}").contains("is_nullable: true"));
+
+        let mut props = PropertyVec::new();
+        props.add_property(id.clone());
+        props.add_property(tags.clone());
+        let pg = PropertyGroup::new(props, FileType::Parquet, "").unwrap();
+        assert_eq!(pg.properties(), vec![id, tags]);
     }
 
     #[test]
@@ -888,7 +1143,7 @@ mod tests {
             false,
             Cardinality::Single,
         ));
-        let pg = PropertyGroup::new(props, FileType::Csv, "");
+        let pg = PropertyGroup::new(props, FileType::Csv, "").unwrap();
         assert!(pg.has_property("id"));
         assert!(pg.has_property("name"));
         assert!(!pg.has_property("missing"));
@@ -905,7 +1160,7 @@ mod tests {
 
     // `VertexInfo`
     #[test]
-    fn test_vertex_info_new_dump_and_save() -> anyhow::Result<()> {
+    fn test_vertex_info_new_dump_and_save() -> Result<()> {
         let mut props = PropertyVec::new();
         props.add_property(Property::new(
             "id",
@@ -914,12 +1169,12 @@ mod tests {
             false,
             Cardinality::Single,
         ));
-        let pg = PropertyGroup::new(props, FileType::Csv, "");
+        let pg = PropertyGroup::new(props, FileType::Csv, "")?;
         let mut pgv = PropertyGroupVector::new();
         pgv.add_property_group(pg);
         let ver = InfoVersion::new(1)?;
 
-        let vi = VertexInfo::new("person".into(), 2, pgv, vec![], "", ver);
+        let vi = VertexInfo::new("person".into(), 2, pgv, vec![], "", ver)?;
         let dump = vi.dump()?;
         assert!(dump.contains("type: person"));
         assert!(dump.contains("chunk_size: 2"));
@@ -934,7 +1189,7 @@ mod tests {
 
     // `EdgeInfo`
     #[test]
-    fn test_edge_info_new_getters_dump_and_save() -> anyhow::Result<()> {
+    fn test_edge_info_new_getters_dump_and_save() -> Result<()> {
         let mut adjs = AdjacentListVector::new();
         adjs.add_adjacent_list(AdjacentList::new(
             AdjListType::OrderedBySource,
@@ -951,12 +1206,12 @@ mod tests {
             Cardinality::Single,
         ));
         let mut pgv = PropertyGroupVector::new();
-        pgv.add_property_group(PropertyGroup::new(props, FileType::Csv, "weight/"));
+        pgv.add_property_group(PropertyGroup::new(props, FileType::Csv, "weight/")?);
 
         let ver = InfoVersion::new(1)?;
         let ei = EdgeInfo::new(
             "person", "knows", "person", 10, 2, 2, true, adjs, pgv, "", ver,
-        );
+        )?;
         assert_eq!(ei.src_type(), "person");
         assert_eq!(ei.dst_type(), "person");
         assert_eq!(ei.edge_type(), "knows");
@@ -976,7 +1231,7 @@ mod tests {
 
     // `GraphInfo`
     #[test]
-    fn test_graph_info_new_build_and_dump() -> anyhow::Result<()> {
+    fn test_graph_info_new_build_and_dump() -> Result<()> {
         // VertexInfo
         let mut vp = PropertyVec::new();
         vp.add_property(Property::new(
@@ -986,7 +1241,7 @@ mod tests {
             false,
             Cardinality::Single,
         ));
-        let pg = PropertyGroup::new(vp, FileType::Csv, "");
+        let pg = PropertyGroup::new(vp, FileType::Csv, "")?;
         let mut pgv = PropertyGroupVector::new();
         pgv.add_property_group(pg);
         let ver = InfoVersion::new(1)?;
@@ -997,7 +1252,7 @@ mod tests {
             vec!["Person".into()],
             "",
             ver.clone(),
-        );
+        )?;
 
         // EdgeInfo
         let mut adjs = AdjacentListVector::new();
@@ -1015,16 +1270,16 @@ mod tests {
             Cardinality::Single,
         ));
         let mut epg = PropertyGroupVector::new();
-        epg.add_property_group(PropertyGroup::new(ep, FileType::Csv, "w/"));
+        epg.add_property_group(PropertyGroup::new(ep, FileType::Csv, "w/")?);
         let ei = EdgeInfo::new(
             "person", "knows", "person", 10, 2, 2, true, adjs, epg, "", ver,
-        );
+        )?;
 
         // GraphInfo::new
         let name = "my_graph".to_string();
         let labels = vec!["Person".to_string()];
         let prefix = "test_graph/".to_string();
-        let g = GraphInfo::new(&name, &vec![vi], &vec![ei], &labels, &prefix, None);
+        let g = GraphInfo::new(&name, &vec![vi], &vec![ei], &labels, &prefix, None)?;
 
         assert_eq!(g.name(), name);
         assert_eq!(g.vertex_info_num(), 1);
@@ -1061,7 +1316,7 @@ mod tests {
             false,
             Cardinality::Single,
         ));
-        let pg = PropertyGroup::new(props, FileType::Csv, "props/");
+        let pg = PropertyGroup::new(props, FileType::Csv, "props/").unwrap();
         let items: Vec<(String, DataType)> = pg
             .properties()
             .into_iter()
@@ -1074,7 +1329,7 @@ mod tests {
     }
 
     #[test]
-    fn test_vertex_info_property_groups_index() -> anyhow::Result<()> {
+    fn test_vertex_info_property_groups_index() -> Result<()> {
         // Build two property groups
         let mut props1 = PropertyVec::new();
         props1.add_property(Property::new(
@@ -1084,7 +1339,7 @@ mod tests {
             false,
             Cardinality::Single,
         ));
-        let pg1 = PropertyGroup::new(props1, FileType::Csv, "id/");
+        let pg1 = PropertyGroup::new(props1, FileType::Csv, "id/")?;
 
         let mut props2 = PropertyVec::new();
         props2.add_property(Property::new(
@@ -1094,25 +1349,25 @@ mod tests {
             false,
             Cardinality::Single,
         ));
-        let pg2 = PropertyGroup::new(props2, FileType::Csv, "name/");
+        let pg2 = PropertyGroup::new(props2, FileType::Csv, "name/")?;
 
         let mut pgv = PropertyGroupVector::new();
         pgv.add_property_group(pg1);
         pgv.add_property_group(pg2);
 
         let ver = InfoVersion::new(1)?;
-        let vi = VertexInfo::new("person".into(), 2, pgv, vec![], "", ver);
+        let vi = VertexInfo::new("person".into(), 2, pgv, vec![], "", ver)?;
         assert_eq!(vi.property_group_num(), 2);
 
-        let pg_first = vi.property_group_by_index(0);
+        let pg_first = vi.property_group_by_index(0).unwrap();
         assert!(pg_first.has_property("id"));
-        let pg_second = vi.property_group_by_index(1);
+        let pg_second = vi.property_group_by_index(1).unwrap();
         assert!(pg_second.has_property("name"));
         Ok(())
     }
 
     #[test]
-    fn test_edge_info_adjacent_list_and_property_group_index() -> anyhow::Result<()> {
+    fn test_edge_info_adjacent_list_and_property_group_index() -> Result<()> {
         let mut adjs = AdjacentListVector::new();
         adjs.add_adjacent_list(AdjacentList::new(
             AdjListType::OrderedBySource,
@@ -1129,24 +1384,28 @@ mod tests {
             Cardinality::Single,
         ));
         let mut epg = PropertyGroupVector::new();
-        epg.add_property_group(PropertyGroup::new(ep, FileType::Csv, "weight/"));
+        epg.add_property_group(PropertyGroup::new(ep, FileType::Csv, "weight/")?);
 
         let ver = InfoVersion::new(1)?;
         let ei = EdgeInfo::new(
             "person", "knows", "person", 10, 2, 2, true, adjs, epg, "", ver,
-        );
+        )?;
 
         assert!(ei.has_adjacent_list_type(AdjListType::OrderedBySource));
         assert!(!ei.has_adjacent_list_type(AdjListType::OrderedByDest));
 
-        let pg0 = ei.property_group_by_index(0);
+        let pg0 = ei.property_group_by_index(0).unwrap();
         assert!(pg0.has_property("weight"));
+        assert!(ei.property_group_by_index(1).is_none());
+        assert!(ei.property_group("missing").is_none());
+        assert!(ei.adjacent_list(AdjListType::OrderedBySource).is_some());
+        assert!(ei.adjacent_list(AdjListType::OrderedByDest).is_none());
 
         Ok(())
     }
 
     #[test]
-    fn test_graph_info_indices() -> anyhow::Result<()> {
+    fn test_graph_info_indices() -> Result<()> {
         // Vertex infos: person then software
         let mut vertex_prop_vec1 = PropertyVec::new();
         vertex_prop_vec1.add_property(Property::new(
@@ -1161,7 +1420,7 @@ mod tests {
             vertex_prop_vec1,
             FileType::Csv,
             "id/",
-        ));
+        )?);
         let ver = InfoVersion::new(1)?;
         let vertex_info_person = VertexInfo::new(
             "person".into(),
@@ -1170,7 +1429,7 @@ mod tests {
             vec![],
             "",
             ver.clone(),
-        );
+        )?;
 
         let mut vertex_prop_vec2 = PropertyVec::new();
         vertex_prop_vec2.add_property(Property::new(
@@ -1185,7 +1444,7 @@ mod tests {
             vertex_prop_vec2,
             FileType::Csv,
             "id/",
-        ));
+        )?);
         let vertex_info_software = VertexInfo::new(
             "software".into(),
             2,
@@ -1193,7 +1452,7 @@ mod tests {
             vec![],
             "",
             ver.clone(),
-        );
+        )?;
 
         // Edge info
         let mut adjs = AdjacentListVector::new();
@@ -1215,7 +1474,7 @@ mod tests {
             edge_prop_vec,
             FileType::Csv,
             "weight/",
-        ));
+        )?);
         let edge_info = EdgeInfo::new(
             "person",
             "knows",
@@ -1228,7 +1487,7 @@ mod tests {
             edge_prop_group_vec,
             "",
             ver,
-        );
+        )?;
 
         let graph_info = GraphInfo::new(
             "g",
@@ -1237,13 +1496,313 @@ mod tests {
             &vec![],
             "prefix/",
             None,
-        );
+        )?;
 
-        assert_eq!(graph_info.vertex_info_index("person"), 0);
-        assert_eq!(graph_info.vertex_info_index("software"), 1);
-        assert_eq!(graph_info.edge_info_index("person", "knows", "person"), 0);
+        assert_eq!(graph_info.vertex_info_index("person"), Some(0));
+        assert_eq!(graph_info.vertex_info_index("software"), Some(1));
+        assert_eq!(
+            graph_info.edge_info_index("person", "knows", "person"),
+            Some(0)
+        );
+        assert_eq!(graph_info.vertex_info_index("robot"), None);
+        assert_eq!(
+            graph_info.edge_info_index("person", "likes", "person"),
+            None
+        );
+        assert!(graph_info.vertex_info("robot").is_none());
+        assert!(graph_info.edge_info("person", "likes", "person").is_none());
         assert_eq!(graph_info.vertex_infos().len(), 2);
         assert_eq!(graph_info.edge_infos().len(), 1);
         Ok(())
     }
+
+    fn single_property_group(name: &str) -> PropertyGroup {
+        let mut props = PropertyVec::new();
+        props.add_property(Property::new(
+            name,
+            &DataType::int64(),
+            false,
+            false,
+            Cardinality::Single,
+        ));
+        PropertyGroup::new(props, FileType::Parquet, "").unwrap()
+    }
+
+    #[test]
+    fn test_vertex_info_add_property_group() -> Result<()> {
+        let mut pgv = PropertyGroupVector::new();
+        pgv.add_property_group(single_property_group("id"));
+        let vi = VertexInfo::new("person".into(), 2, pgv, vec![], "", InfoVersion::new(1)?)?;
+
+        let extended = vi.add_property_group(single_property_group("age"))?;
+        assert_eq!(extended.property_group_num(), 2);
+        assert!(
+            extended
+                .property_group_by_index(1)
+                .unwrap()
+                .has_property("age")
+        );
+        // The original info is left untouched.
+        assert_eq!(vi.property_group_num(), 1);
+
+        assert!(matches!(
+            extended.add_property_group(single_property_group("age")),
+            Err(Error::Invalid(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_info_add_property_group_and_adjacent_list() -> Result<()> {
+        let mut adjs = AdjacentListVector::new();
+        adjs.add_adjacent_list(AdjacentList::new(
+            AdjListType::OrderedBySource,
+            FileType::Parquet,
+            "ordered_by_source/",
+        ));
+        let ei = EdgeInfo::new(
+            "person",
+            "knows",
+            "person",
+            10,
+            2,
+            2,
+            true,
+            adjs,
+            PropertyGroupVector::new(),
+            "",
+            InfoVersion::new(1)?,
+        )?;
+
+        let by_dest = || {
+            AdjacentList::new(
+                AdjListType::OrderedByDest,
+                FileType::Parquet,
+                "ordered_by_dest/",
+            )
+        };
+        let extended = ei
+            .add_adjacent_list(by_dest())?
+            .add_property_group(single_property_group("weight"))?;
+        assert!(extended.has_adjacent_list_type(AdjListType::OrderedByDest));
+        assert_eq!(extended.property_group_num(), 1);
+        assert!(!ei.has_adjacent_list_type(AdjListType::OrderedByDest));
+        assert_eq!(ei.property_group_num(), 0);
+
+        assert!(extended.add_adjacent_list(by_dest()).is_err());
+        assert!(
+            extended
+                .add_property_group(single_property_group("weight"))
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_info_add_vertex_and_edge() -> Result<()> {
+        let vertex_info = |ty: &str| -> Result<VertexInfo> {
+            let mut pgv = PropertyGroupVector::new();
+            pgv.add_property_group(single_property_group("id"));
+            VertexInfo::new(ty.into(), 2, pgv, vec![], "", InfoVersion::new(1)?)
+        };
+        let edge_info = |edge_type: &str| -> Result<EdgeInfo> {
+            let mut adjs = AdjacentListVector::new();
+            adjs.add_adjacent_list(AdjacentList::new(
+                AdjListType::OrderedBySource,
+                FileType::Parquet,
+                "ordered_by_source/",
+            ));
+            EdgeInfo::new(
+                "person",
+                edge_type,
+                "person",
+                10,
+                2,
+                2,
+                true,
+                adjs,
+                PropertyGroupVector::new(),
+                "",
+                InfoVersion::new(1)?,
+            )
+        };
+
+        let gi = GraphInfo::new(
+            "g",
+            &vec![vertex_info("person")?],
+            &vec![],
+            &vec![],
+            "prefix/",
+            None,
+        )?;
+        let extended = gi
+            .add_vertex(vertex_info("software")?)?
+            .add_edge(edge_info("knows")?)?;
+        assert_eq!(extended.vertex_info_num(), 2);
+        assert_eq!(
+            extended.edge_info_index("person", "knows", "person"),
+            Some(0)
+        );
+        assert_eq!(gi.vertex_info_num(), 1);
+        assert_eq!(gi.edge_info_num(), 0);
+
+        assert!(extended.add_vertex(vertex_info("person")?).is_err());
+        assert!(extended.add_edge(edge_info("knows")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_info_constructors_reject_invalid_arguments() -> Result<()> {
+        assert!(matches!(
+            PropertyGroup::new(PropertyVec::new(), FileType::Parquet, ""),
+            Err(Error::Invalid(_))
+        ));
+
+        let mut pgv = PropertyGroupVector::new();
+        pgv.add_property_group(single_property_group("id"));
+        let vi = VertexInfo::new("person".into(), 0, pgv, vec![], "", InfoVersion::new(1)?);
+        assert!(matches!(vi, Err(Error::Invalid(_))));
+
+        let ei = EdgeInfo::new(
+            "person",
+            "knows",
+            "person",
+            10,
+            2,
+            2,
+            true,
+            AdjacentListVector::new(),
+            PropertyGroupVector::new(),
+            "",
+            InfoVersion::new(1)?,
+        );
+        assert!(matches!(ei, Err(Error::Invalid(_))));
+
+        let gi = GraphInfo::new("", &vec![], &vec![], &vec![], "prefix/", None);
+        assert!(matches!(gi, Err(Error::Invalid(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_info_version_is_optional() -> Result<()> {
+        let g = GraphInfo::new("g", &vec![], &vec![], &vec![], "prefix/", None)?;
+        assert!(g.version().is_none());
+
+        let g = GraphInfo::new(
+            "g",
+            &vec![],
+            &vec![],
+            &vec![],
+            "prefix/",
+            Some(InfoVersion::new(1)?),
+        )?;
+        assert!(g.version().is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_info_extra_info_roundtrip() -> Result<()> {
+        let g = GraphInfo::new("g", &vec![], &vec![], &vec![], "prefix/", None)?;
+        assert!(g.extra_info().is_empty());
+
+        let extra_info = BTreeMap::from([
+            ("license".to_string(), "CC-BY-4.0".to_string()),
+            ("source".to_string(), "snapshot-2024-01".to_string()),
+        ]);
+        let g = g.with_extra_info(extra_info.clone());
+        assert_eq!(g.extra_info(), extra_info);
+        assert_eq!(g.name(), "g");
+        assert!(g.dump()?.contains("snapshot-2024-01"));
+
+        let dir = tempdir()?;
+        let path = dir.path().join("g.graph.yml");
+        g.save(&path)?;
+        assert_eq!(GraphInfo::load(&path)?.extra_info(), extra_info);
+        Ok(())
+    }
+
+    #[test]
+    fn test_info_file_paths() -> Result<()> {
+        let group = |name: &str| {
+            let mut props = PropertyVec::new();
+            props.add_property(Property::new(
+                name,
+                &DataType::int64(),
+                false,
+                false,
+                Cardinality::Single,
+            ));
+            PropertyGroup::new(props, FileType::Parquet, format!("{name}/")).unwrap()
+        };
+        let ver = InfoVersion::new(1)?;
+
+        let mut pgv = PropertyGroupVector::new();
+        pgv.add_property_group(group("id"));
+        let vi = VertexInfo::new(
+            "person".into(),
+            2,
+            pgv,
+            vec![],
+            "vertex/person/",
+            ver.clone(),
+        )?;
+        let id = vi.property_group("id").unwrap();
+        assert_eq!(
+            vi.property_file_path(&id, 3)?,
+            Path::new("vertex/person/id/chunk3")
+        );
+        assert_eq!(
+            vi.vertices_num_file_path()?,
+            Path::new("vertex/person/vertex_count")
+        );
+
+        let mut adjs = AdjacentListVector::new();
+        adjs.add_adjacent_list(AdjacentList::new(
+            AdjListType::OrderedBySource,
+            FileType::Parquet,
+            "ordered_by_source/",
+        ));
+        let mut epg = PropertyGroupVector::new();
+        epg.add_property_group(group("weight"));
+        let ei = EdgeInfo::new(
+            "person",
+            "knows",
+            "person",
+            4,
+            2,
+            2,
+            true,
+            adjs,
+            epg,
+            "edge/knows/",
+            ver,
+        )?;
+        let ty = AdjListType::OrderedBySource;
+        let weight = ei.property_group("weight").unwrap();
+        assert_eq!(
+            ei.vertices_num_file_path(ty)?,
+            Path::new("edge/knows/ordered_by_source/vertex_count")
+        );
+        assert_eq!(
+            ei.edges_num_file_path(1, ty)?,
+            Path::new("edge/knows/ordered_by_source/edge_count1")
+        );
+        assert_eq!(
+            ei.adj_list_file_path(1, 2, ty)?,
+            Path::new("edge/knows/ordered_by_source/adj_list/part1/chunk2")
+        );
+        assert_eq!(
+            ei.adj_list_offset_file_path(1, ty)?,
+            Path::new("edge/knows/ordered_by_source/offset/chunk1")
+        );
+        assert_eq!(
+            ei.property_file_path(&weight, ty, 1, 2)?,
+            Path::new("edge/knows/ordered_by_source/weight/part1/chunk2")
+        );
+        assert!(matches!(
+            ei.adj_list_file_path(0, 0, AdjListType::OrderedByDest),
+            Err(Error::Key(_))
+        ));
+        Ok(())
+    }
 }
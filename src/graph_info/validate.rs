@@ -0,0 +1,361 @@
+use std::{
+    collections::BTreeSet,
+    ffi::OsString,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use cxx::let_cxx_string;
+
+use super::{AdjListType, EdgeInfo, GraphInfo, VertexInfo};
+use crate::{Error, Result, ffi::graphar, graph_reader::EdgeTriple};
+
+const ADJ_LIST_TYPES: [AdjListType; 4] = [
+    AdjListType::UnorderedBySource,
+    AdjListType::UnorderedByDest,
+    AdjListType::OrderedBySource,
+    AdjListType::OrderedByDest,
+];
+
+/// The data files GraphAr writes for a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    /// The vertex count of a vertex type or of an adjacency list.
+    VertexCount,
+    /// The edge count of one vertex chunk of an adjacency list.
+    EdgeCount,
+    VertexChunk,
+    AdjListChunk,
+    OffsetChunk,
+    EdgePropertyChunk,
+}
+
+impl Display for FileKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FileKind::VertexCount => "vertex count",
+            FileKind::EdgeCount => "edge count",
+            FileKind::VertexChunk => "vertex chunk",
+            FileKind::AdjListChunk => "adjacency list chunk",
+            FileKind::OffsetChunk => "offset chunk",
+            FileKind::EdgePropertyChunk => "edge property chunk",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A problem found by [`GraphInfo::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Issue {
+    /// GraphAr's `IsValidated` rejects the graph info.
+    InvalidGraphInfo,
+    /// GraphAr's `IsValidated` rejects the info of a vertex type, whose
+    /// files are then not checked.
+    InvalidVertexInfo(String),
+    /// Same as [`Issue::InvalidVertexInfo`], for an edge type.
+    InvalidEdgeInfo(EdgeTriple),
+    MissingFile {
+        kind: FileKind,
+        path: PathBuf,
+    },
+    /// A file in a data directory that no chunk of the graph maps to.
+    ExtraFile(PathBuf),
+    /// Files that could not be checked, e.g. because their count file is
+    /// unreadable.
+    Unchecked(Error),
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::InvalidGraphInfo => write!(f, "invalid graph info"),
+            Issue::InvalidVertexInfo(ty) => write!(f, "invalid vertex info `{ty}`"),
+            Issue::InvalidEdgeInfo(triple) => write!(f, "invalid edge info `{triple}`"),
+            Issue::MissingFile { kind, path } => {
+                write!(f, "missing {kind} file `{}`", path.display())
+            }
+            Issue::ExtraFile(path) => write!(f, "extra file `{}`", path.display()),
+            Issue::Unchecked(err) => write!(f, "unchecked: {err}"),
+        }
+    }
+}
+
+/// The result of [`GraphInfo::validate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    pub fn missing_files(&self) -> impl Iterator<Item = &Path> {
+        self.issues.iter().filter_map(|issue| match issue {
+            Issue::MissingFile { path, .. } => Some(path.as_path()),
+            _ => None,
+        })
+    }
+
+    pub fn extra_files(&self) -> impl Iterator<Item = &Path> {
+        self.issues.iter().filter_map(|issue| match issue {
+            Issue::ExtraFile(path) => Some(path.as_path()),
+            _ => None,
+        })
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return write!(f, "valid");
+        }
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
+
+impl GraphInfo {
+    /// Checks the metadata with GraphAr's `IsValidated`, then the data files
+    /// under the graph's prefix on the local file system: the count files,
+    /// one chunk per property group and vertex or edge chunk, and the
+    /// adjacency and offset chunks of every adjacency list. Files in the
+    /// chunk directories that are not expected are reported as extra.
+    pub fn validate(&self) -> ValidationReport {
+        let mut checker = Checker::new(self.prefix());
+        if !self.inner.IsValidated() {
+            checker.issues.push(Issue::InvalidGraphInfo);
+        }
+        for info in self.vertex_infos() {
+            if !info.inner.IsValidated() {
+                checker.issues.push(Issue::InvalidVertexInfo(info.ty()));
+            } else if let Err(err) = checker.check_vertex(&info) {
+                checker.issues.push(Issue::Unchecked(err));
+            }
+        }
+        for info in self.edge_infos() {
+            if !info.inner.IsValidated() {
+                checker.issues.push(Issue::InvalidEdgeInfo(EdgeTriple::new(
+                    info.src_type(),
+                    info.edge_type(),
+                    info.dst_type(),
+                )));
+            } else if let Err(err) = checker.check_edge(&info) {
+                checker.issues.push(Issue::Unchecked(err));
+            }
+        }
+        checker.check_extra_files();
+        ValidationReport {
+            issues: checker.issues,
+        }
+    }
+}
+
+fn chunk_num(num: i64, chunk_size: i64) -> i64 {
+    (num + chunk_size - 1) / chunk_size
+}
+
+struct Checker {
+    // GraphAr reads the count files itself and accepts URIs; `root` is the
+    // prefix as a local path.
+    prefix: String,
+    root: String,
+    expected: BTreeSet<PathBuf>,
+    // Directories holding nothing but chunks, scanned for extra files.
+    chunk_dirs: BTreeSet<PathBuf>,
+    issues: Vec<Issue>,
+}
+
+impl Checker {
+    fn new(prefix: String) -> Self {
+        let root = prefix
+            .strip_prefix("file://")
+            .unwrap_or(&prefix)
+            .to_string();
+        Self {
+            prefix,
+            root,
+            expected: BTreeSet::new(),
+            chunk_dirs: BTreeSet::new(),
+            issues: Vec::new(),
+        }
+    }
+
+    // GraphAr's paths are relative to the graph prefix, which they are
+    // appended to as is.
+    fn path(&self, relative: impl AsRef<Path>) -> PathBuf {
+        let mut path = OsString::from(&self.root);
+        path.push(relative.as_ref());
+        path.into()
+    }
+
+    fn expect(&mut self, kind: FileKind, relative: PathBuf) {
+        let path = self.path(relative);
+        if !path.is_file() {
+            self.issues.push(Issue::MissingFile {
+                kind,
+                path: path.clone(),
+            });
+        }
+        self.expected.insert(path);
+    }
+
+    // Returns `None` if the count file is missing or unreadable.
+    fn count(
+        &mut self,
+        kind: FileKind,
+        relative: PathBuf,
+        read: impl FnOnce(&str) -> Result<i64>,
+    ) -> Option<i64> {
+        let path = self.path(relative);
+        self.expected.insert(path.clone());
+        if !path.is_file() {
+            self.issues.push(Issue::MissingFile { kind, path });
+            return None;
+        }
+        match read(&self.prefix) {
+            Ok(num) => Some(num),
+            Err(err) => {
+                self.issues.push(Issue::Unchecked(err.with_path(path)));
+                None
+            }
+        }
+    }
+
+    fn check_vertex(&mut self, info: &VertexInfo) -> Result<()> {
+        let count_path = info.vertices_num_file_path()?;
+        let Some(num) = self.count(FileKind::VertexCount, count_path, |prefix| {
+            let_cxx_string!(prefix = prefix);
+            Ok(graphar::vertex_info_vertices_num(&prefix, &info.inner)?)
+        }) else {
+            return Ok(());
+        };
+
+        for group in info.property_groups() {
+            for chunk in 0..chunk_num(num, info.chunk_size()) {
+                let path = info.property_file_path(&group, chunk)?;
+                self.expect(FileKind::VertexChunk, path);
+            }
+            let dir = graphar::vertex_info_path_prefix(&info.inner, &group.inner)?;
+            self.chunk_dirs.insert(self.path(dir));
+        }
+        Ok(())
+    }
+
+    fn check_edge(&mut self, info: &EdgeInfo) -> Result<()> {
+        let groups = info.property_groups();
+        for ty in ADJ_LIST_TYPES {
+            if !info.has_adjacent_list_type(ty) {
+                continue;
+            }
+            let (ordered, vertex_chunk_size) = match ty {
+                AdjListType::UnorderedBySource => (false, info.src_chunk_size()),
+                AdjListType::UnorderedByDest => (false, info.dst_chunk_size()),
+                AdjListType::OrderedBySource => (true, info.src_chunk_size()),
+                _ => (true, info.dst_chunk_size()),
+            };
+
+            let count_path = info.vertices_num_file_path(ty)?;
+            let Some(vertex_num) = self.count(FileKind::VertexCount, count_path, |prefix| {
+                let_cxx_string!(prefix = prefix);
+                Ok(graphar::edge_info_vertices_num(&prefix, &info.inner, ty)?)
+            }) else {
+                continue;
+            };
+
+            let mut complete = true;
+            for vertex_chunk in 0..chunk_num(vertex_num, vertex_chunk_size) {
+                if ordered {
+                    let path = info.adj_list_offset_file_path(vertex_chunk, ty)?;
+                    self.expect(FileKind::OffsetChunk, path);
+                }
+
+                let count_path = info.edges_num_file_path(vertex_chunk, ty)?;
+                let Some(edge_num) = self.count(FileKind::EdgeCount, count_path, |prefix| {
+                    let_cxx_string!(prefix = prefix);
+                    Ok(graphar::edge_info_edges_num(
+                        &prefix,
+                        &info.inner,
+                        ty,
+                        vertex_chunk,
+                    )?)
+                }) else {
+                    complete = false;
+                    continue;
+                };
+
+                for edge_chunk in 0..chunk_num(edge_num, info.chunk_size()) {
+                    let path = info.adj_list_file_path(vertex_chunk, edge_chunk, ty)?;
+                    self.expect(FileKind::AdjListChunk, path);
+                    for group in &groups {
+                        let path = info.property_file_path(group, ty, vertex_chunk, edge_chunk)?;
+                        self.expect(FileKind::EdgePropertyChunk, path);
+                    }
+                }
+            }
+
+            // Without every edge count, which chunks belong is unknown.
+            if !complete {
+                continue;
+            }
+            let mut dirs = vec![graphar::edge_info_adj_list_path_prefix(&info.inner, ty)?];
+            if ordered {
+                dirs.push(graphar::edge_info_offset_path_prefix(&info.inner, ty)?);
+            }
+            for group in &groups {
+                dirs.push(graphar::edge_info_property_group_path_prefix(
+                    &info.inner,
+                    &group.inner,
+                    ty,
+                )?);
+            }
+            for dir in dirs {
+                self.chunk_dirs.insert(self.path(dir));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_extra_files(&mut self) {
+        let mut files = BTreeSet::new();
+        for dir in &self.chunk_dirs {
+            match list_files(dir, &mut files) {
+                Ok(()) => {}
+                // Its chunks are reported missing.
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => self
+                    .issues
+                    .push(Issue::Unchecked(Error::from(err).with_path(dir))),
+            }
+        }
+        for file in files {
+            if !self.expected.contains(&file) {
+                self.issues.push(Issue::ExtraFile(file));
+            }
+        }
+    }
+}
+
+fn list_files(dir: &Path, files: &mut BTreeSet<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            list_files(&entry.path(), files)?;
+        } else {
+            files.insert(entry.path());
+        }
+    }
+    Ok(())
+}
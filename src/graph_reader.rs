@@ -1,16 +1,287 @@
-use std::ops::Range;
+use std::{
+    ops::{Deref, DerefMut, Range},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use cxx::{CxxString, CxxVector, SharedPtr, UniquePtr, let_cxx_string, memory::SharedPtrTarget};
 
-use anyhow::Result;
-use cxx::{SharedPtr, UniquePtr, let_cxx_string};
+#[cfg(feature = "tokio")]
+mod async_graph;
+#[cfg(feature = "arrow")]
+pub mod chunk;
+mod expr;
+mod graph;
+mod neighbors;
+#[cfg(feature = "rayon")]
+mod par;
+
+#[cfg(feature = "tokio")]
+pub use async_graph::AsyncGraph;
+pub use expr::{Expr, Literal, col, lit};
+pub use graph::Graph;
+pub use neighbors::{EdgeTriple, NeighborReader};
 
 use crate::{
-    cxx_string_to_string,
-    ffi::graphar::{self, vertex_iter_deref},
-    graph_info::{AdjListType, GraphInfo},
+    Error, Result, cxx_string_to_string,
+    ffi::graphar::{self, edge_iter_deref, vertex_iter_deref},
+    graph_info::{AdjListType, Cardinality, GraphInfo, PropertyGroup, Type, VertexInfo},
+    types::{Date, PropertyValue, Timestamp},
 };
 
+// Property metadata of one vertex or edge type, shared by a collection and
+// everything it yields.
+#[derive(Debug, Default)]
+struct Schema {
+    properties: Vec<PropertyMeta>,
+}
+
+#[derive(Debug)]
+struct PropertyMeta {
+    name: String,
+    nullable: bool,
+    ty: Type,
+    // Element type of `list<T>` columns and of list/set cardinality properties.
+    element_ty: Option<Type>,
+}
+
+impl Schema {
+    fn new(groups: Vec<PropertyGroup>) -> Self {
+        let properties = groups
+            .iter()
+            .flat_map(|group| group.properties())
+            .map(|prop| {
+                let data_type = prop.data_type();
+                let ty = data_type.id();
+                let element_ty = if ty == Type::List {
+                    data_type.value_type().map(|value_type| value_type.id())
+                } else if prop.cardinality() != Cardinality::Single {
+                    Some(ty)
+                } else {
+                    None
+                };
+                PropertyMeta {
+                    name: prop.name(),
+                    nullable: prop.is_nullable(),
+                    ty,
+                    element_ty,
+                }
+            })
+            .collect();
+        Self { properties }
+    }
+
+    fn get(&self, name: &str) -> Option<&PropertyMeta> {
+        self.properties.iter().find(|prop| prop.name == name)
+    }
+
+    // Unknown properties are reported as non-nullable so the typed read
+    // surfaces GraphAr's "property not found" error.
+    fn is_nullable(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|prop| prop.nullable)
+    }
+}
+
+// Collections are only touched under their lock. A panic while holding it
+// cannot leave the C++ collection half-updated, so poisoning is ignored.
+fn lock<T>(inner: &Mutex<T>) -> MutexGuard<'_, T> {
+    inner.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// A GraphAr collection owned by the mutex of a `Vertices` or `Edges`.
+struct Collection<T: SharedPtrTarget>(SharedPtr<T>);
+
+// SAFETY: collections cache iterators in their non-const methods, so they
+// cannot be shared freely. A `Collection` is only ever stored inside a
+// `Mutex`, which hands it to one thread at a time.
+unsafe impl Send for Collection<graphar::VerticesCollection> {}
+unsafe impl Send for Collection<graphar::EdgesCollection> {}
+
+impl<T: SharedPtrTarget> Collection<T> {
+    fn locked(inner: SharedPtr<T>) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self(inner)))
+    }
+}
+
+impl<T: SharedPtrTarget> Deref for Collection<T> {
+    type Target = SharedPtr<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: SharedPtrTarget> DerefMut for Collection<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+// Common ground of `Vertex`, `VertexIter`, `Edge` and `EdgeIter` for the
+// schema-driven accessors.
+trait PropertySource {
+    fn schema(&self) -> &Schema;
+    fn is_valid(&mut self, name: &str) -> bool;
+    fn read<T>(&mut self, name: &str) -> Result<T>
+    where
+        (): SupportedPropertyType<T>;
+}
+
+impl PropertySource for &Vertex {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn is_valid(&mut self, name: &str) -> bool {
+        Vertex::is_valid(self, name)
+    }
+
+    fn read<T>(&mut self, name: &str) -> Result<T>
+    where
+        (): SupportedPropertyType<T>,
+    {
+        self.property(name)
+    }
+}
+
+impl PropertySource for VertexIter {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn is_valid(&mut self, name: &str) -> bool {
+        VertexIter::is_valid(self, name)
+    }
+
+    fn read<T>(&mut self, name: &str) -> Result<T>
+    where
+        (): SupportedPropertyType<T>,
+    {
+        self.property(name)
+    }
+}
+
+impl PropertySource for &Edge {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn is_valid(&mut self, name: &str) -> bool {
+        Edge::is_valid(self, name)
+    }
+
+    fn read<T>(&mut self, name: &str) -> Result<T>
+    where
+        (): SupportedPropertyType<T>,
+    {
+        self.property(name)
+    }
+}
+
+impl PropertySource for EdgeIter {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn is_valid(&mut self, name: &str) -> bool {
+        EdgeIter::is_valid(self, name)
+    }
+
+    fn read<T>(&mut self, name: &str) -> Result<T>
+    where
+        (): SupportedPropertyType<T>,
+    {
+        self.property(name)
+    }
+}
+
+fn read_value<S: PropertySource>(source: &mut S, name: &str) -> Result<PropertyValue> {
+    let meta = source
+        .schema()
+        .get(name)
+        .ok_or_else(|| Error::key("property not found").with_property(name))?;
+    let (nullable, ty, element_ty) = (meta.nullable, meta.ty, meta.element_ty);
+
+    if nullable && !source.is_valid(name) {
+        return Ok(PropertyValue::Null);
+    }
+    if let Some(element_ty) = element_ty {
+        return read_list(source, name, element_ty);
+    }
+
+    Ok(match ty {
+        Type::Bool => PropertyValue::Bool(source.read(name)?),
+        Type::Int32 => PropertyValue::Int32(source.read(name)?),
+        Type::Int64 => PropertyValue::Int64(source.read(name)?),
+        Type::Float => PropertyValue::Float(source.read(name)?),
+        Type::Double => PropertyValue::Double(source.read(name)?),
+        Type::String => PropertyValue::String(source.read(name)?),
+        Type::Date => PropertyValue::Date(source.read(name)?),
+        Type::Timestamp => PropertyValue::Timestamp(source.read(name)?),
+        _ => {
+            return Err(Error::type_error(format!("unsupported type {ty:?}")).with_property(name));
+        }
+    })
+}
+
+fn read_list<S: PropertySource>(
+    source: &mut S,
+    name: &str,
+    element_ty: Type,
+) -> Result<PropertyValue> {
+    let values = match element_ty {
+        Type::Int32 => source
+            .read::<Vec<i32>>(name)?
+            .into_iter()
+            .map(PropertyValue::Int32)
+            .collect(),
+        Type::Int64 => source
+            .read::<Vec<i64>>(name)?
+            .into_iter()
+            .map(PropertyValue::Int64)
+            .collect(),
+        Type::Float => source
+            .read::<Vec<f32>>(name)?
+            .into_iter()
+            .map(PropertyValue::Float)
+            .collect(),
+        Type::Double => source
+            .read::<Vec<f64>>(name)?
+            .into_iter()
+            .map(PropertyValue::Double)
+            .collect(),
+        Type::String => source
+            .read::<Vec<String>>(name)?
+            .into_iter()
+            .map(PropertyValue::String)
+            .collect(),
+        _ => {
+            let message = format!("unsupported list element type {element_ty:?}");
+            return Err(Error::type_error(message).with_property(name));
+        }
+    };
+    Ok(PropertyValue::List(values))
+}
+
+fn read_all<S: PropertySource>(mut source: S) -> Result<Vec<(String, PropertyValue)>> {
+    let names: Vec<String> = source
+        .schema()
+        .properties
+        .iter()
+        .map(|prop| prop.name.clone())
+        .collect();
+    names
+        .into_iter()
+        .map(|name| {
+            let value = read_value(&mut source, &name)?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
 pub struct Vertex {
     inner: UniquePtr<graphar::Vertex>,
+    schema: Arc<Schema>,
 }
 
 impl Vertex {
@@ -28,12 +299,27 @@ impl Vertex {
         (): SupportedPropertyType<T>,
     {
         <() as SupportedPropertyType<T>>::vertex_property(self, name)
+            .map_err(|err| err.with_property(name))
+    }
+
+    /// Reads `name` according to its declared data type.
+    pub fn get(&self, name: &str) -> Result<PropertyValue> {
+        let mut source = self;
+        read_value(&mut source, name)
+    }
+
+    /// Reads every property of all property groups, in declaration order.
+    pub fn properties(&self) -> Result<Vec<(String, PropertyValue)>> {
+        read_all(self)
     }
 }
 
 pub struct VertexIter {
     iter: UniquePtr<graphar::VertexIter>,
-    len: usize,
+    // Filtered collections yield non-contiguous ids, so the end is detected by
+    // comparing against the collection's end iterator.
+    end: UniquePtr<graphar::VertexIter>,
+    schema: Arc<Schema>,
 }
 
 impl VertexIter {
@@ -58,21 +344,34 @@ impl VertexIter {
         Ok(out)
     }
 
-    pub fn next(&mut self) {
+    /// Moves to the next vertex without reading the current one.
+    pub fn advance(&mut self) {
         graphar::vertex_iter_next(self.iter.pin_mut());
     }
 
     pub fn vertex(&mut self) -> Vertex {
         Vertex {
             inner: vertex_iter_deref(self.iter.pin_mut()),
+            schema: self.schema.clone(),
         }
     }
 
+    pub fn is_valid(&mut self, property: &str) -> bool {
+        let_cxx_string!(prop = property);
+        graphar::vertex_iter_is_valid(self.iter.pin_mut(), &prop)
+    }
+
     pub fn property<T>(&mut self, name: &str) -> Result<T>
     where
         (): SupportedPropertyType<T>,
     {
         <() as SupportedPropertyType<T>>::vertex_iter_property(self, name)
+            .map_err(|err| err.with_property(name))
+    }
+
+    /// Reads `name` according to its declared data type.
+    pub fn get(&mut self, name: &str) -> Result<PropertyValue> {
+        read_value(self, name)
     }
 }
 
@@ -88,26 +387,159 @@ impl Iterator for VertexIter {
     type Item = Vertex;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.id() as usize == self.len {
+        if graphar::vertex_iter_eq(&self.iter, &self.end) {
             None
         } else {
             let ret = self.vertex();
-            self.next();
+            self.advance();
             Some(ret)
         }
     }
 }
 
+/// How [`Vertices::with_labels`] combines several labels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelMatch {
+    /// Vertices carrying every label.
+    All,
+    /// Vertices carrying at least one of the labels.
+    Any,
+}
+
+fn string_vector(values: &[&str]) -> UniquePtr<CxxVector<CxxString>> {
+    graphar::new_string_vector(values)
+}
+
+/// A filter accepted by [`Vertices::filter`] and [`Vertices::filter_ids`].
+#[derive(Clone)]
+pub enum VertexFilter {
+    /// Vertices carrying every one of the labels.
+    Labels(Vec<String>),
+    /// Vertices whose property satisfies the expression.
+    Property { name: String, filter: Expr },
+}
+
+impl VertexFilter {
+    pub fn labels(labels: &[&str]) -> Self {
+        Self::Labels(labels.iter().map(|label| label.to_string()).collect())
+    }
+
+    pub fn property(name: &str, filter: Expr) -> Self {
+        Self::Property {
+            name: name.to_string(),
+            filter,
+        }
+    }
+}
+
+/// Sorted ids of the vertices matching a filter, and the chunks holding them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilteredIds {
+    ids: Vec<i64>,
+    chunks: Vec<i64>,
+}
+
+impl FilteredIds {
+    fn new(mut ids: Vec<i64>, mut chunks: Vec<i64>) -> Self {
+        ids.sort_unstable();
+        ids.dedup();
+        chunks.sort_unstable();
+        chunks.dedup();
+        Self { ids, chunks }
+    }
+
+    pub fn ids(&self) -> &[i64] {
+        &self.ids
+    }
+
+    pub fn chunks(&self) -> &[i64] {
+        &self.chunks
+    }
+
+    pub fn contains(&self, id: i64) -> bool {
+        self.ids.binary_search(&id).is_ok()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn into_ids(self) -> Vec<i64> {
+        self.ids
+    }
+}
+
+/// A collection of vertices of one type.
+///
+/// Clones share the underlying GraphAr collection, and calls into it are
+/// serialized by a lock, so a `Vertices` can be shared across threads (it is
+/// `Send + Sync`). Iterators own their readers and only hold the lock while
+/// being created; each thread can iterate its own `VertexIter` concurrently.
 #[derive(Clone)]
 pub struct Vertices {
-    inner: SharedPtr<graphar::VerticesCollection>,
+    inner: Arc<Mutex<Collection<graphar::VerticesCollection>>>,
+    schema: Arc<Schema>,
+    graph_info: SharedPtr<graphar::GraphInfo>,
+    ty: String,
+    #[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+    chunk_size: i64,
+    selection: Selection,
+}
+
+// How a collection selects the vertices of its type.
+#[derive(Clone)]
+enum Selection {
+    // Every vertex, so ids are contiguous.
+    All,
+    // Filtered by GraphAr, which knows the chunks holding the matches.
+    Filtered,
+    // The union of filtered collections (`LabelMatch::Any`), read through a
+    // plain id list. GraphAr does not know which chunks the ids live in, so
+    // filters refine each part within its own chunks instead.
+    Union(Arc<[Vertices]>),
 }
 
 impl Vertices {
+    fn from_inner(
+        graph_info: &SharedPtr<graphar::GraphInfo>,
+        ty: &str,
+        inner: SharedPtr<graphar::VerticesCollection>,
+        schema: Option<Arc<Schema>>,
+        selection: Selection,
+    ) -> Self {
+        let_cxx_string!(ty_cxx = ty);
+        let vertex_info = graph_info.GetVertexInfo(&ty_cxx);
+        let schema = schema.unwrap_or_else(|| {
+            let groups = VertexInfo {
+                inner: vertex_info.clone(),
+            }
+            .property_groups();
+            Arc::new(Schema::new(groups))
+        });
+        Self {
+            inner: Collection::locked(inner),
+            schema,
+            graph_info: graph_info.clone(),
+            ty: ty.to_string(),
+            chunk_size: vertex_info.GetChunkSize(),
+            selection,
+        }
+    }
+
     pub fn new(graph_info: &GraphInfo, ty: &str) -> Result<Self> {
         let_cxx_string!(ty_cxx = ty);
         let inner = graphar::vertices_collection_make(&graph_info.inner, &ty_cxx)?;
-        Ok(Self { inner })
+        Ok(Self::from_inner(
+            &graph_info.inner,
+            ty,
+            inner,
+            None,
+            Selection::All,
+        ))
     }
 
     pub fn with_label(graph_info: &GraphInfo, ty: &str, label: &str) -> Result<Self> {
@@ -115,45 +547,241 @@ impl Vertices {
         let_cxx_string!(label_cxx = label);
         let inner =
             graphar::vertices_collection_with_label(&graph_info.inner, &ty_cxx, &label_cxx)?;
-        Ok(Self { inner })
+        Ok(Self::from_inner(
+            &graph_info.inner,
+            ty,
+            inner,
+            None,
+            Selection::Filtered,
+        ))
+    }
+
+    /// Vertices carrying the given labels, combined according to `mode`.
+    pub fn with_labels(
+        graph_info: &GraphInfo,
+        ty: &str,
+        labels: &[&str],
+        mode: LabelMatch,
+    ) -> Result<Self> {
+        let_cxx_string!(ty_cxx = ty);
+        match mode {
+            LabelMatch::All => {
+                let labels = string_vector(labels);
+                let inner =
+                    graphar::vertices_collection_with_labels(&graph_info.inner, &ty_cxx, &labels)?;
+                Ok(Self::from_inner(
+                    &graph_info.inner,
+                    ty,
+                    inner,
+                    None,
+                    Selection::Filtered,
+                ))
+            }
+            LabelMatch::Any => {
+                // GraphAr only intersects labels, so union the single-label matches.
+                let mut all = graphar::vertices_collection_make(&graph_info.inner, &ty_cxx)?;
+                let mut ids = Vec::new();
+                let mut parts = Vec::with_capacity(labels.len());
+                for label in labels {
+                    let filter = string_vector(&[label]);
+                    let matched =
+                        unsafe { graphar::filter_by_label(all.pin_mut_unchecked(), &filter)? };
+                    ids.extend(matched.iter().copied());
+                    parts.push(Self::with_label(graph_info, ty, label)?);
+                }
+                Self::union(&graph_info.inner, ty, None, parts, ids)
+            }
+        }
+    }
+
+    /// Vertices whose property `name` satisfies `filter`.
+    pub fn with_property(
+        graph_info: &GraphInfo,
+        ty: &str,
+        name: &str,
+        filter: Expr,
+    ) -> Result<Self> {
+        let_cxx_string!(ty_cxx = ty);
+        let_cxx_string!(name_cxx = name);
+        let inner = graphar::vertices_collection_with_property(
+            &graph_info.inner,
+            &ty_cxx,
+            &name_cxx,
+            &filter.into(),
+        )?;
+        Ok(Self::from_inner(
+            &graph_info.inner,
+            ty,
+            inner,
+            None,
+            Selection::Filtered,
+        ))
+    }
+
+    // Reads the union of `parts` through their matching `ids`.
+    fn union(
+        graph_info: &SharedPtr<graphar::GraphInfo>,
+        ty: &str,
+        schema: Option<Arc<Schema>>,
+        parts: Vec<Vertices>,
+        mut ids: Vec<i64>,
+    ) -> Result<Self> {
+        ids.sort_unstable();
+        ids.dedup();
+        let_cxx_string!(ty_cxx = ty);
+        let inner = graphar::vertices_collection_with_ids(graph_info, &ty_cxx, &ids)?;
+        Ok(Self::from_inner(
+            graph_info,
+            ty,
+            inner,
+            schema,
+            Selection::Union(parts.into()),
+        ))
+    }
+
+    /// Narrows this collection to the vertices matching `filter`.
+    ///
+    /// On a collection that is already filtered, GraphAr only reads the chunks
+    /// that still hold matches, so chaining filters gets cheaper at each step.
+    pub fn filter(&self, filter: &VertexFilter) -> Result<Vertices> {
+        if let Selection::Union(parts) = &self.selection {
+            let (ids, _) = self.matches(filter)?;
+            let parts = parts
+                .iter()
+                .map(|part| part.filter(filter))
+                .collect::<Result<Vec<_>>>()?;
+            return Vertices::union(
+                &self.graph_info,
+                &self.ty,
+                Some(self.schema.clone()),
+                parts,
+                ids,
+            );
+        }
+
+        let inner = match filter {
+            VertexFilter::Labels(labels) => {
+                let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+                graphar::vertices_collection_refine_with_labels(
+                    &lock(&self.inner),
+                    &string_vector(&labels),
+                )?
+            }
+            VertexFilter::Property { name, filter } => {
+                let_cxx_string!(name_cxx = name);
+                graphar::vertices_collection_refine_with_property(
+                    &lock(&self.inner),
+                    &name_cxx,
+                    &filter.clone().into(),
+                )?
+            }
+        };
+        Ok(Vertices::from_inner(
+            &self.graph_info,
+            &self.ty,
+            inner,
+            Some(self.schema.clone()),
+            Selection::Filtered,
+        ))
+    }
+
+    /// Ids of the vertices in this collection matching `filter`, together
+    /// with the chunks that contain them.
+    pub fn filter_ids(&self, filter: &VertexFilter) -> Result<FilteredIds> {
+        let (ids, chunks) = self.matches(filter)?;
+        Ok(FilteredIds::new(ids, chunks))
+    }
+
+    // Ids of the vertices matching `filter` and the chunks holding them, read
+    // from GraphAr's filter without building a collection. Only the chunks
+    // still selected by this collection are read.
+    fn matches(&self, filter: &VertexFilter) -> Result<(Vec<i64>, Vec<i64>)> {
+        if let Selection::Union(parts) = &self.selection {
+            let (mut ids, mut chunks) = (Vec::new(), Vec::new());
+            for part in parts.iter() {
+                let (part_ids, part_chunks) = part.matches(filter)?;
+                ids.extend(part_ids);
+                chunks.extend(part_chunks);
+            }
+            return Ok((ids, chunks));
+        }
+
+        let mut chunks = CxxVector::new();
+        let mut inner = lock(&self.inner);
+        // The lock gives exclusive access to the collection, see `iter_at`.
+        let ids = match filter {
+            VertexFilter::Labels(labels) => {
+                let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+                unsafe {
+                    graphar::filter_by_label_with_chunk(
+                        inner.pin_mut_unchecked(),
+                        &string_vector(&labels),
+                        chunks.pin_mut(),
+                    )?
+                }
+            }
+            VertexFilter::Property { name, filter } => {
+                let_cxx_string!(name_cxx = name);
+                unsafe {
+                    graphar::filter_by_property_name_with_chunk(
+                        inner.pin_mut_unchecked(),
+                        &name_cxx,
+                        filter.clone().into(),
+                        chunks.pin_mut(),
+                    )?
+                }
+            }
+        };
+        Ok((
+            ids.iter().copied().collect(),
+            chunks.iter().copied().collect(),
+        ))
     }
 
     pub fn len(&self) -> usize {
-        self.inner.size()
+        lock(&self.inner).size()
     }
 
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    pub fn begin(&mut self) -> VertexIter {
-        let begin = unsafe { graphar::vertices_collection_begin(self.inner.pin_mut_unchecked()) };
+    pub fn begin(&self) -> VertexIter {
+        self.iter_at(graphar::vertices_collection_begin)
+    }
 
-        VertexIter {
-            iter: begin,
-            len: self.len(),
-        }
+    pub fn end(&self) -> VertexIter {
+        self.iter_at(graphar::vertices_collection_end)
     }
 
-    pub fn end(&mut self) -> VertexIter {
-        let iter = unsafe { graphar::vertices_collection_end(self.inner.pin_mut_unchecked()) };
-        VertexIter {
-            iter,
-            len: self.len(),
-        }
+    pub fn find(&self, id: i64) -> VertexIter {
+        self.iter_at(|inner| graphar::vertices_collection_find(inner, id))
     }
 
-    pub fn find(&mut self, id: i64) -> VertexIter {
-        let iter = unsafe { graphar::vertices_collection_find(self.inner.pin_mut_unchecked(), id) };
-        VertexIter {
-            iter,
-            len: self.len(),
-        }
+    // `at` gets exclusive access to the collection for as long as the lock is
+    // held, which makes the unchecked pins sound.
+    fn iter_at(
+        &self,
+        at: impl FnOnce(Pin<&mut graphar::VerticesCollection>) -> UniquePtr<graphar::VertexIter>,
+    ) -> VertexIter {
+        vertex_iter(&mut lock(&self.inner), self.schema.clone(), at)
     }
 }
 
+// Only sound while the caller has exclusive access to `inner`.
+fn vertex_iter(
+    inner: &mut SharedPtr<graphar::VerticesCollection>,
+    schema: Arc<Schema>,
+    at: impl FnOnce(Pin<&mut graphar::VerticesCollection>) -> UniquePtr<graphar::VertexIter>,
+) -> VertexIter {
+    let iter = at(unsafe { inner.pin_mut_unchecked() });
+    let end = unsafe { graphar::vertices_collection_end(inner.pin_mut_unchecked()) };
+    VertexIter { iter, end, schema }
+}
+
 pub struct Edge {
     inner: UniquePtr<graphar::Edge>,
+    schema: Arc<Schema>,
 }
 
 impl Edge {
@@ -175,14 +803,34 @@ impl Edge {
         (): SupportedPropertyType<T>,
     {
         <() as SupportedPropertyType<T>>::edge_property(self, name)
+            .map_err(|err| err.with_property(name))
+    }
+
+    /// Reads `name` according to its declared data type.
+    pub fn get(&self, name: &str) -> Result<PropertyValue> {
+        let mut source = self;
+        read_value(&mut source, name)
+    }
+
+    /// Reads every property of all property groups, in declaration order.
+    pub fn properties(&self) -> Result<Vec<(String, PropertyValue)>> {
+        read_all(self)
     }
 }
 
+/// Iterator over an [`Edges`] collection.
+///
+/// `EdgeIter` implements [`Iterator`], yielding an [`Edge`] per step. The
+/// remaining length is tracked from [`Edges::len`], so it is also an
+/// [`ExactSizeIterator`]. Jumping between sources or destinations skips an
+/// unknown number of edges and is done on an [`EdgeCursor`] instead.
 pub struct EdgeIter {
     iter: UniquePtr<graphar::EdgeIter>,
+    len: usize,
+    remaining: usize,
+    schema: Arc<Schema>,
 }
 
-// TODO(wrapper for EdgeIter like VertexIter)
 impl EdgeIter {
     pub fn source(&mut self) -> i64 {
         self.iter.pin_mut().source()
@@ -192,28 +840,140 @@ impl EdgeIter {
         self.iter.pin_mut().destination()
     }
 
-    pub fn next(&mut self) {
+    /// Moves to the next edge without reading the current one.
+    pub fn advance(&mut self) {
         graphar::edge_iter_next(self.iter.pin_mut());
+        self.remaining = self.remaining.saturating_sub(1);
+    }
+
+    pub fn edge(&mut self) -> Edge {
+        Edge {
+            inner: edge_iter_deref(self.iter.pin_mut()),
+            schema: self.schema.clone(),
+        }
+    }
+
+    pub fn is_valid(&mut self, property: &str) -> bool {
+        let_cxx_string!(prop = property);
+        graphar::edge_iter_is_valid(self.iter.pin_mut(), &prop)
     }
 
     pub fn to_begin(&mut self) {
         graphar::edge_iter_to_begin(self.iter.pin_mut());
+        self.remaining = self.len;
+    }
+
+    pub fn global_chunk_index(&self) -> i64 {
+        self.iter.global_chunk_index()
+    }
+
+    pub fn cur_offset(&self) -> i64 {
+        self.iter.cur_offset()
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.iter.is_end()
+    }
+
+    pub fn property<T>(&mut self, name: &str) -> Result<T>
+    where
+        (): SupportedPropertyType<T>,
+    {
+        <() as SupportedPropertyType<T>>::edge_iter_property(self, name)
+            .map_err(|err| err.with_property(name))
+    }
+
+    /// Reads `name` according to its declared data type.
+    pub fn get(&mut self, name: &str) -> Result<PropertyValue> {
+        read_value(self, name)
+    }
+}
+
+impl PartialEq for EdgeIter {
+    fn eq(&self, other: &Self) -> bool {
+        graphar::edge_iter_eq(&self.iter, &other.iter)
+    }
+}
+
+impl Eq for EdgeIter {}
+
+impl Iterator for EdgeIter {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_end() {
+            self.remaining = 0;
+            None
+        } else {
+            let ret = self.edge();
+            self.advance();
+            Some(ret)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_end() {
+            (0, Some(0))
+        } else {
+            (self.remaining, Some(self.remaining))
+        }
+    }
+}
+
+impl ExactSizeIterator for EdgeIter {}
+
+impl std::iter::FusedIterator for EdgeIter {}
+
+/// Cursor over an [`Edges`] collection that can jump between sources and
+/// destinations.
+///
+/// Like [`EdgeIter`], an `EdgeCursor` yields an [`Edge`] per step, but after a
+/// jump the number of edges left is unknown, so [`Iterator::size_hint`] only
+/// reports an upper bound.
+pub struct EdgeCursor {
+    iter: EdgeIter,
+}
+
+impl EdgeCursor {
+    pub fn source(&mut self) -> i64 {
+        self.iter.source()
+    }
+
+    pub fn destination(&mut self) -> i64 {
+        self.iter.destination()
+    }
+
+    /// Moves to the next edge without reading the current one.
+    pub fn advance(&mut self) {
+        self.iter.advance();
+    }
+
+    pub fn edge(&mut self) -> Edge {
+        self.iter.edge()
+    }
+
+    pub fn is_valid(&mut self, property: &str) -> bool {
+        self.iter.is_valid(property)
+    }
+
+    pub fn to_begin(&mut self) {
+        self.iter.to_begin();
     }
 
     pub fn next_src(&mut self) -> bool {
-        graphar::edge_iter_next_src(self.iter.pin_mut())
+        graphar::edge_iter_next_src(self.iter.iter.pin_mut())
     }
 
     pub fn next_dst(&mut self) -> bool {
-        graphar::edge_iter_next_dst(self.iter.pin_mut())
+        graphar::edge_iter_next_dst(self.iter.iter.pin_mut())
     }
 
     pub fn next_src_with_id(&mut self, id: i64) -> bool {
-        graphar::edge_iter_next_src_with_id(self.iter.pin_mut(), id)
+        graphar::edge_iter_next_src_with_id(self.iter.iter.pin_mut(), id)
     }
 
     pub fn next_dst_with_id(&mut self, id: i64) -> bool {
-        graphar::edge_iter_next_dst_with_id(self.iter.pin_mut(), id)
+        graphar::edge_iter_next_dst_with_id(self.iter.iter.pin_mut(), id)
     }
 
     pub fn global_chunk_index(&self) -> i64 {
@@ -232,21 +992,61 @@ impl EdgeIter {
     where
         (): SupportedPropertyType<T>,
     {
-        <() as SupportedPropertyType<T>>::edge_iter_property(self, name)
+        self.iter.property(name)
+    }
+
+    /// Reads `name` according to its declared data type.
+    pub fn get(&mut self, name: &str) -> Result<PropertyValue> {
+        self.iter.get(name)
     }
 }
 
-impl PartialEq for EdgeIter {
+impl From<EdgeIter> for EdgeCursor {
+    fn from(iter: EdgeIter) -> Self {
+        Self { iter }
+    }
+}
+
+impl PartialEq for EdgeCursor {
     fn eq(&self, other: &Self) -> bool {
-        graphar::edge_iter_eq(&self.iter, &other.iter)
+        self.iter == other.iter
     }
 }
 
-impl Eq for EdgeIter {}
+impl Eq for EdgeCursor {}
+
+impl Iterator for EdgeCursor {
+    type Item = Edge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_end() {
+            (0, Some(0))
+        } else {
+            (0, Some(self.iter.remaining))
+        }
+    }
+}
+
+impl std::iter::FusedIterator for EdgeCursor {}
 
+/// A collection of edges read through one adjacency list.
+///
+/// Like [`Vertices`], clones share the underlying GraphAr collection behind a
+/// lock, and an `Edges` is `Send + Sync`.
 #[derive(Clone)]
 pub struct Edges {
-    inner: SharedPtr<graphar::EdgesCollection>,
+    inner: Arc<Mutex<Collection<graphar::EdgesCollection>>>,
+    schema: Arc<Schema>,
+    // What the collection was opened with, to reopen it per vertex chunk.
+    #[cfg_attr(not(feature = "rayon"), allow(dead_code))]
+    graph_info: GraphInfo,
+    triple: EdgeTriple,
+    adj_list_type: AdjListType,
+    vertex_chunks: Range<i64>,
 }
 
 impl Edges {
@@ -276,43 +1076,211 @@ impl Edges {
             chunk_begin,
             chunk_end,
         )?;
-
-        Ok(Self { inner })
+        let triple = EdgeTriple::new(src_type, edge_type, dst_type);
+        let edge_info = neighbors::edge_info(graph_info, &triple)?;
+        let schema = Arc::new(Schema::new(edge_info.property_groups()));
+
+        Ok(Self {
+            inner: Collection::locked(inner),
+            schema,
+            graph_info: graph_info.clone(),
+            triple,
+            adj_list_type,
+            vertex_chunks: chunk_begin..chunk_end,
+        })
     }
 
     pub fn len(&self) -> usize {
-        self.inner.size()
+        lock(&self.inner).size()
     }
 
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
-    pub fn begin(&mut self) -> EdgeIter {
-        let iter = unsafe { graphar::edges_collection_begin(self.inner.pin_mut_unchecked()) };
-        EdgeIter { iter }
+    pub fn edge_triple(&self) -> &EdgeTriple {
+        &self.triple
     }
 
-    pub fn end(&mut self) -> EdgeIter {
-        let iter = unsafe { graphar::edges_collection_end(self.inner.pin_mut_unchecked()) };
-        EdgeIter { iter }
+    pub fn adj_list_type(&self) -> AdjListType {
+        self.adj_list_type
     }
 
-    pub fn find_src(&mut self, id: i64, from: &EdgeIter) -> EdgeIter {
-        let iter = unsafe {
-            graphar::edges_collection_find_src(self.inner.pin_mut_unchecked(), id, &from.iter)
-        };
-        EdgeIter { iter }
+    /// The vertex chunks this collection was opened with, `0..i64::MAX` for
+    /// all of them.
+    pub fn vertex_chunks(&self) -> Range<i64> {
+        self.vertex_chunks.clone()
+    }
+
+    pub fn begin(&self) -> EdgeIter {
+        self.iter_at(None, graphar::edges_collection_begin)
+    }
+
+    pub fn end(&self) -> EdgeIter {
+        self.iter_at(Some(0), graphar::edges_collection_end)
     }
 
-    pub fn find_dst(&mut self, id: i64, from: &EdgeIter) -> EdgeIter {
-        let iter = unsafe {
-            graphar::edges_collection_find_dst(self.inner.pin_mut_unchecked(), id, &from.iter)
+    /// A cursor at the first edge, for `find_src`/`find_dst` and jumps.
+    pub fn cursor(&self) -> EdgeCursor {
+        self.begin().into()
+    }
+
+    pub fn find_src(&self, id: i64, from: &EdgeCursor) -> EdgeCursor {
+        self.iter_at(Some(from.iter.remaining), |inner| {
+            graphar::edges_collection_find_src(inner, id, &from.iter.iter)
+        })
+        .into()
+    }
+
+    pub fn find_dst(&self, id: i64, from: &EdgeCursor) -> EdgeCursor {
+        self.iter_at(Some(from.iter.remaining), |inner| {
+            graphar::edges_collection_find_dst(inner, id, &from.iter.iter)
+        })
+        .into()
+    }
+
+    // See `Vertices::iter_at`. `remaining` defaults to the whole collection.
+    fn iter_at(
+        &self,
+        remaining: Option<usize>,
+        at: impl FnOnce(Pin<&mut graphar::EdgesCollection>) -> UniquePtr<graphar::EdgeIter>,
+    ) -> EdgeIter {
+        let mut inner = lock(&self.inner);
+        let len = inner.size();
+        EdgeIter {
+            iter: at(unsafe { inner.pin_mut_unchecked() }),
+            len,
+            remaining: remaining.unwrap_or(len),
+            schema: self.schema.clone(),
+        }
+    }
+}
+
+impl IntoIterator for Edges {
+    type Item = Edge;
+    type IntoIter = EdgeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.begin()
+    }
+}
+
+impl IntoIterator for &mut Edges {
+    type Item = Edge;
+    type IntoIter = EdgeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.begin()
+    }
+}
+
+impl IntoIterator for &Edges {
+    type Item = Edge;
+    type IntoIter = EdgeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.begin()
+    }
+}
+
+/// Offsets (CSR) index of an ordered adjacency list, for constant-time edge
+/// range and degree lookups.
+///
+/// GraphAr partitions ordered adjacency lists by vertex chunk, so ranges are
+/// relative to the first edge of the vertex chunk holding the vertex, which
+/// is also what [`EdgeCursor::cur_offset`] reports.
+#[derive(Debug, Clone)]
+pub struct Adjacency {
+    adj_list_type: AdjListType,
+    vertex_chunk_size: i64,
+    // One offsets array per vertex chunk, with one more entry than vertices.
+    offsets: Vec<Vec<i64>>,
+}
+
+impl Adjacency {
+    /// Reads all offset chunks of an `OrderedBySource` or `OrderedByDest`
+    /// adjacency list. Vertex ids are sources for the former and destinations
+    /// for the latter.
+    pub fn new(
+        graph_info: &GraphInfo,
+        src_type: &str,
+        edge_type: &str,
+        dst_type: &str,
+        adj_list_type: AdjListType,
+    ) -> Result<Self> {
+        let edge_info =
+            neighbors::edge_info(graph_info, &EdgeTriple::new(src_type, edge_type, dst_type))?;
+        let vertex_chunk_size = match adj_list_type {
+            AdjListType::OrderedBySource => edge_info.src_chunk_size(),
+            AdjListType::OrderedByDest => edge_info.dst_chunk_size(),
+            _ => {
+                return Err(Error::invalid(
+                    "offsets are only stored for ordered adjacency lists",
+                ));
+            }
         };
-        EdgeIter { iter }
+
+        let_cxx_string!(src_cxx = src_type);
+        let_cxx_string!(edge_cxx = edge_type);
+        let_cxx_string!(dst_cxx = dst_type);
+        let mut reader = graphar::new_adj_list_offset_reader(
+            &graph_info.inner,
+            &src_cxx,
+            &edge_cxx,
+            &dst_cxx,
+            adj_list_type,
+        )?;
+
+        let mut offsets = Vec::new();
+        loop {
+            offsets.push(unsafe {
+                graphar::adj_list_offset_reader_chunk(reader.pin_mut_unchecked())?
+            });
+            if !unsafe { graphar::adj_list_offset_reader_next_chunk(reader.pin_mut_unchecked())? } {
+                break;
+            }
+        }
+
+        Ok(Self {
+            adj_list_type,
+            vertex_chunk_size,
+            offsets,
+        })
+    }
+
+    pub fn adj_list_type(&self) -> AdjListType {
+        self.adj_list_type
+    }
+
+    /// Number of vertex ids covered by the offset chunks.
+    pub fn vertex_num(&self) -> i64 {
+        self.offsets
+            .iter()
+            .map(|chunk| chunk.len().saturating_sub(1) as i64)
+            .sum()
+    }
+
+    /// Returns the `[begin, end)` edge range of vertex `id` within its vertex
+    /// chunk, or `None` if `id` is out of range.
+    pub fn range(&self, id: i64) -> Option<Range<i64>> {
+        if id < 0 {
+            return None;
+        }
+        let chunk = self.offsets.get((id / self.vertex_chunk_size) as usize)?;
+        let local = (id % self.vertex_chunk_size) as usize;
+        Some(*chunk.get(local)?..*chunk.get(local + 1)?)
+    }
+
+    pub fn degree(&self, id: i64) -> Option<usize> {
+        self.range(id)
+            .map(|range| (range.end - range.start) as usize)
     }
 }
 
+#[diagnostic::on_unimplemented(
+    message = "`{T}` cannot be read as a GraphAr property",
+    note = "lists are supported for `i32`, `i64`, `f32`, `f64` and `String` elements only"
+)]
 pub trait SupportedPropertyType<T> {
     fn vertex_property(vertex: &Vertex, name: &str) -> Result<T>;
     fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<T>;
@@ -320,6 +1288,40 @@ pub trait SupportedPropertyType<T> {
     fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<T>;
 }
 
+// Non-nullable columns skip the validity check and read the value directly.
+impl<T> SupportedPropertyType<Option<T>> for ()
+where
+    (): SupportedPropertyType<T>,
+{
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Option<T>> {
+        if vertex.schema.is_nullable(name) && !vertex.is_valid(name) {
+            return Ok(None);
+        }
+        <() as SupportedPropertyType<T>>::vertex_property(vertex, name).map(Some)
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Option<T>> {
+        if iter.schema.is_nullable(name) && !iter.is_valid(name) {
+            return Ok(None);
+        }
+        <() as SupportedPropertyType<T>>::vertex_iter_property(iter, name).map(Some)
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Option<T>> {
+        if edge.schema.is_nullable(name) && !edge.is_valid(name) {
+            return Ok(None);
+        }
+        <() as SupportedPropertyType<T>>::edge_property(edge, name).map(Some)
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Option<T>> {
+        if iter.schema.is_nullable(name) && !iter.is_valid(name) {
+            return Ok(None);
+        }
+        <() as SupportedPropertyType<T>>::edge_iter_property(iter, name).map(Some)
+    }
+}
+
 impl SupportedPropertyType<bool> for () {
     fn vertex_property(vertex: &Vertex, name: &str) -> Result<bool> {
         let_cxx_string!(prop = name);
@@ -475,3 +1477,211 @@ impl SupportedPropertyType<String> for () {
         )?)
     }
 }
+
+impl SupportedPropertyType<Date> for () {
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Date> {
+        let_cxx_string!(prop = name);
+        Ok(Date::from_days(graphar::vertex_property_date(
+            &vertex.inner,
+            &prop,
+        )?))
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Date> {
+        let_cxx_string!(prop = name);
+        Ok(Date::from_days(graphar::vertex_iter_property_date(
+            iter.iter.pin_mut(),
+            &prop,
+        )?))
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Date> {
+        let_cxx_string!(prop = name);
+        Ok(Date::from_days(graphar::edge_property_date(
+            &edge.inner,
+            &prop,
+        )?))
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Date> {
+        let_cxx_string!(prop = name);
+        Ok(Date::from_days(graphar::edge_iter_property_date(
+            iter.iter.pin_mut(),
+            &prop,
+        )?))
+    }
+}
+
+impl SupportedPropertyType<Timestamp> for () {
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Timestamp> {
+        let_cxx_string!(prop = name);
+        Ok(Timestamp::from_millis(graphar::vertex_property_timestamp(
+            &vertex.inner,
+            &prop,
+        )?))
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Timestamp> {
+        let_cxx_string!(prop = name);
+        Ok(Timestamp::from_millis(
+            graphar::vertex_iter_property_timestamp(iter.iter.pin_mut(), &prop)?,
+        ))
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Timestamp> {
+        let_cxx_string!(prop = name);
+        Ok(Timestamp::from_millis(graphar::edge_property_timestamp(
+            &edge.inner,
+            &prop,
+        )?))
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Timestamp> {
+        let_cxx_string!(prop = name);
+        Ok(Timestamp::from_millis(
+            graphar::edge_iter_property_timestamp(iter.iter.pin_mut(), &prop)?,
+        ))
+    }
+}
+
+// Only numeric and string lists have GraphAr array views; `list<bool>`,
+// `list<date>` and `list<timestamp>` columns are rejected with a type error.
+impl SupportedPropertyType<Vec<i32>> for () {
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Vec<i32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_property_list_i32(&vertex.inner, &prop)?)
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Vec<i32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_iter_property_list_i32(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Vec<i32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_property_list_i32(&edge.inner, &prop)?)
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Vec<i32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_iter_property_list_i32(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+}
+
+impl SupportedPropertyType<Vec<i64>> for () {
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Vec<i64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_property_list_i64(&vertex.inner, &prop)?)
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Vec<i64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_iter_property_list_i64(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Vec<i64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_property_list_i64(&edge.inner, &prop)?)
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Vec<i64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_iter_property_list_i64(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+}
+
+impl SupportedPropertyType<Vec<f32>> for () {
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Vec<f32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_property_list_f32(&vertex.inner, &prop)?)
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Vec<f32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_iter_property_list_f32(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Vec<f32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_property_list_f32(&edge.inner, &prop)?)
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Vec<f32>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_iter_property_list_f32(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+}
+
+impl SupportedPropertyType<Vec<f64>> for () {
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Vec<f64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_property_list_f64(&vertex.inner, &prop)?)
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Vec<f64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_iter_property_list_f64(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Vec<f64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_property_list_f64(&edge.inner, &prop)?)
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Vec<f64>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_iter_property_list_f64(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+}
+
+impl SupportedPropertyType<Vec<String>> for () {
+    fn vertex_property(vertex: &Vertex, name: &str) -> Result<Vec<String>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_property_list_string(&vertex.inner, &prop)?)
+    }
+
+    fn vertex_iter_property(iter: &mut VertexIter, name: &str) -> Result<Vec<String>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::vertex_iter_property_list_string(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+
+    fn edge_property(edge: &Edge, name: &str) -> Result<Vec<String>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_property_list_string(&edge.inner, &prop)?)
+    }
+
+    fn edge_iter_property(iter: &mut EdgeIter, name: &str) -> Result<Vec<String>> {
+        let_cxx_string!(prop = name);
+        Ok(graphar::edge_iter_property_list_string(
+            iter.iter.pin_mut(),
+            &prop,
+        )?)
+    }
+}
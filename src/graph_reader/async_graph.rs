@@ -0,0 +1,209 @@
+//! A tokio front end to the blocking readers. Requires the `tokio` feature.
+
+use std::path::Path;
+
+use tokio::{sync::mpsc, task};
+use tokio_stream::{Stream, wrappers::ReceiverStream};
+
+use super::{
+    Edge, Edges, Vertex, VertexFilter, Vertices, graph::preferred_adj_list_type,
+    neighbors::EdgeTriple,
+};
+use crate::{Error, Result, graph_info::GraphInfo};
+
+const DEFAULT_BUFFER: usize = 1024;
+
+/// Async access to a graph for tokio services.
+///
+/// File I/O runs on tokio's blocking pool, one chunk per task. Streams are fed
+/// through a bounded channel of [`AsyncGraph::with_buffer`] items: a consumer
+/// that falls behind pauses the reader without holding a blocking thread, and
+/// dropping the stream stops it before the next chunk. Streams must be
+/// created inside a tokio runtime.
+#[derive(Clone)]
+pub struct AsyncGraph {
+    info: GraphInfo,
+    buffer: usize,
+}
+
+impl AsyncGraph {
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let info = blocking(move || GraphInfo::load(path)).await?;
+        Ok(Self::new(info))
+    }
+
+    pub fn new(info: GraphInfo) -> Self {
+        Self {
+            info,
+            buffer: DEFAULT_BUFFER,
+        }
+    }
+
+    /// Sets how many items a stream reads ahead of its consumer.
+    pub fn with_buffer(mut self, buffer: usize) -> Self {
+        self.buffer = buffer.max(1);
+        self
+    }
+
+    pub fn info(&self) -> &GraphInfo {
+        &self.info
+    }
+
+    pub fn vertices(&self, ty: &str) -> impl Stream<Item = Result<Vertex>> + Unpin + Send {
+        let info = self.info.clone();
+        let step = self.vertex_chunk_size(ty);
+        let ty = ty.to_string();
+        self.stream(step, move || Ok(Vertices::new(&info, &ty)?.begin().map(Ok)))
+    }
+
+    pub fn filtered_vertices(
+        &self,
+        ty: &str,
+        filter: VertexFilter,
+    ) -> impl Stream<Item = Result<Vertex>> + Unpin + Send {
+        let info = self.info.clone();
+        let step = self.vertex_chunk_size(ty);
+        let ty = ty.to_string();
+        self.stream(step, move || {
+            let vertices = Vertices::new(&info, &ty)?.filter(&filter)?;
+            Ok(vertices.begin().map(Ok))
+        })
+    }
+
+    /// Streams the edges through the adjacency list
+    /// [`Graph::edges`](super::Graph::edges) would pick.
+    pub fn edges(
+        &self,
+        src_type: &str,
+        edge_type: &str,
+        dst_type: &str,
+    ) -> impl Stream<Item = Result<Edge>> + Unpin + Send {
+        let info = self.info.clone();
+        let triple = EdgeTriple::new(src_type, edge_type, dst_type);
+        let step = chunk_step(
+            self.info
+                .edge_info(src_type, edge_type, dst_type)
+                .map(|info| info.chunk_size()),
+        );
+        self.stream(step, move || {
+            let adj_list_type = preferred_adj_list_type(&info, &triple)?;
+            let edges = Edges::new(
+                &info,
+                &triple.src_type,
+                &triple.edge_type,
+                &triple.dst_type,
+                adj_list_type,
+                None,
+            )?;
+            Ok(edges.begin().map(Ok))
+        })
+    }
+
+    /// Streams the chunks of the property group holding `property` as arrow
+    /// record batches. Requires the `arrow` feature.
+    #[cfg(feature = "arrow")]
+    pub fn vertex_batches(
+        &self,
+        ty: &str,
+        property: &str,
+    ) -> impl Stream<Item = Result<arrow::array::RecordBatch>> + Unpin + Send {
+        let info = self.info.clone();
+        let ty = ty.to_string();
+        let property = property.to_string();
+        self.stream(1, move || {
+            super::chunk::VertexChunkReader::with_property(&info, &ty, &property)
+        })
+    }
+
+    /// Streams the chunks of an adjacency list as arrow record batches.
+    /// Requires the `arrow` feature.
+    #[cfg(feature = "arrow")]
+    pub fn adj_list_batches(
+        &self,
+        src_type: &str,
+        edge_type: &str,
+        dst_type: &str,
+        adj_list_type: crate::graph_info::AdjListType,
+    ) -> impl Stream<Item = Result<arrow::array::RecordBatch>> + Unpin + Send {
+        let info = self.info.clone();
+        let triple = EdgeTriple::new(src_type, edge_type, dst_type);
+        self.stream(1, move || {
+            super::chunk::AdjListChunkReader::new(
+                &info,
+                &triple.src_type,
+                &triple.edge_type,
+                &triple.dst_type,
+                adj_list_type,
+            )
+        })
+    }
+
+    fn vertex_chunk_size(&self, ty: &str) -> usize {
+        chunk_step(self.info.vertex_info(ty).map(|info| info.chunk_size()))
+    }
+
+    // Runs `open`, then reads `step` items per blocking task. Items are sent
+    // from an async task, so a full channel holds no blocking thread.
+    fn stream<T, I>(
+        &self,
+        step: usize,
+        open: impl FnOnce() -> Result<I> + Send + 'static,
+    ) -> ReceiverStream<Result<T>>
+    where
+        T: Send + 'static,
+        I: Iterator<Item = Result<T>> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel(self.buffer);
+        tokio::spawn(async move {
+            let mut items = match blocking(open).await {
+                Ok(items) => items,
+                Err(err) => {
+                    let _ = tx.send(Err(err)).await;
+                    return;
+                }
+            };
+            // The receiver is gone once the stream is dropped.
+            while !tx.is_closed() {
+                let read = blocking(move || {
+                    let chunk: Vec<_> = items.by_ref().take(step).collect();
+                    Ok((items, chunk))
+                })
+                .await;
+                let chunk = match read {
+                    Ok((rest, chunk)) => {
+                        items = rest;
+                        chunk
+                    }
+                    Err(err) => {
+                        let _ = tx.send(Err(err)).await;
+                        return;
+                    }
+                };
+                let done = chunk.len() < step;
+                for item in chunk {
+                    if tx.send(item).await.is_err() {
+                        return;
+                    }
+                }
+                if done {
+                    return;
+                }
+            }
+        });
+        ReceiverStream::new(rx)
+    }
+}
+
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> Result<T> + Send + 'static) -> Result<T> {
+    task::spawn_blocking(f)
+        .await
+        .map_err(|err| Error::unknown(err.to_string()))?
+}
+
+fn chunk_step(chunk_size: Option<i64>) -> usize {
+    chunk_size
+        .and_then(|size| usize::try_from(size).ok())
+        .unwrap_or(1)
+        .max(1)
+}
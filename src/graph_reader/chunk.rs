@@ -0,0 +1,220 @@
+//! Columnar access to GraphAr chunks as arrow-rs [`RecordBatch`]es.
+//!
+//! Chunks are read by GraphAr's arrow chunk readers and moved to Rust through
+//! the Arrow C Data Interface, so the column buffers are shared rather than
+//! copied. Requires the `arrow` feature.
+
+use arrow::{
+    array::{RecordBatch, StructArray},
+    ffi::{FFI_ArrowArray, FFI_ArrowSchema, from_ffi},
+};
+use std::pin::Pin;
+
+use cxx::{SharedPtr, let_cxx_string};
+
+use crate::{
+    Error, Result,
+    ffi::graphar,
+    graph_info::{AdjListType, GraphInfo, PropertyGroup},
+};
+
+type Export<R> = unsafe fn(Pin<&mut R>, usize, usize) -> std::result::Result<(), cxx::Exception>;
+
+// `export` is one of the `*_chunk_reader_export` bridge functions.
+fn import_batch<R>(reader: Pin<&mut R>, export: Export<R>) -> Result<RecordBatch> {
+    let mut array = FFI_ArrowArray::empty();
+    let mut schema = FFI_ArrowSchema::empty();
+    // SAFETY: `export` writes the current chunk into the two structs, which
+    // are live and correctly typed for the whole call, and `from_ffi` then
+    // takes ownership of the exported array.
+    let data = unsafe {
+        export(
+            reader,
+            &mut array as *mut FFI_ArrowArray as usize,
+            &mut schema as *mut FFI_ArrowSchema as usize,
+        )?;
+        from_ffi(array, &schema)?
+    };
+    Ok(RecordBatch::from(StructArray::from(data)))
+}
+
+/// Reads the chunks of one vertex property group.
+///
+/// As an [`Iterator`], yields every chunk from the current position on. An
+/// error ends the iteration after it is yielded.
+pub struct VertexChunkReader {
+    inner: SharedPtr<graphar::VertexPropertyArrowChunkReader>,
+    done: bool,
+    // A failure to move past the last yielded chunk, yielded next.
+    error: Option<Error>,
+}
+
+impl VertexChunkReader {
+    pub fn new(graph_info: &GraphInfo, ty: &str, property_group: &PropertyGroup) -> Result<Self> {
+        let_cxx_string!(ty_cxx = ty);
+        let inner =
+            graphar::new_vertex_chunk_reader(&graph_info.inner, &ty_cxx, &property_group.inner)?;
+        Ok(Self {
+            inner,
+            done: false,
+            error: None,
+        })
+    }
+
+    /// Reads the property group containing `property_name`.
+    pub fn with_property(graph_info: &GraphInfo, ty: &str, property_name: &str) -> Result<Self> {
+        let_cxx_string!(ty_cxx = ty);
+        let_cxx_string!(name_cxx = property_name);
+        let inner =
+            graphar::new_vertex_chunk_reader_by_property(&graph_info.inner, &ty_cxx, &name_cxx)?;
+        Ok(Self {
+            inner,
+            done: false,
+            error: None,
+        })
+    }
+
+    /// Moves to the chunk holding vertex `id`.
+    pub fn seek(&mut self, id: i64) -> Result<()> {
+        unsafe { graphar::vertex_chunk_reader_seek(self.inner.pin_mut_unchecked(), id)? };
+        self.done = false;
+        self.error = None;
+        Ok(())
+    }
+
+    /// Reads the current chunk.
+    pub fn chunk(&mut self) -> Result<RecordBatch> {
+        let reader = unsafe { self.inner.pin_mut_unchecked() };
+        import_batch(reader, graphar::vertex_chunk_reader_export)
+    }
+
+    /// Moves to the next chunk, returning `false` past the last one.
+    pub fn next_chunk(&mut self) -> Result<bool> {
+        Ok(unsafe { graphar::vertex_chunk_reader_next_chunk(self.inner.pin_mut_unchecked())? })
+    }
+
+    pub fn chunk_num(&mut self) -> i64 {
+        unsafe { graphar::vertex_chunk_reader_chunk_num(self.inner.pin_mut_unchecked()) }
+    }
+}
+
+impl Iterator for VertexChunkReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            self.done = true;
+            return Some(Err(err));
+        }
+        if self.done {
+            return None;
+        }
+        let batch = self.chunk();
+        if batch.is_err() {
+            self.done = true;
+        } else {
+            match self.next_chunk() {
+                Ok(more) => self.done = !more,
+                Err(err) => self.error = Some(err),
+            }
+        }
+        Some(batch)
+    }
+}
+
+/// Reads the chunks of an adjacency list, with `_graphArSrcIndex` and
+/// `_graphArDstIndex` columns.
+///
+/// As an [`Iterator`], yields every chunk from the current position on, like
+/// [`VertexChunkReader`].
+pub struct AdjListChunkReader {
+    inner: SharedPtr<graphar::AdjListArrowChunkReader>,
+    done: bool,
+    // A failure to move past the last yielded chunk, yielded next.
+    error: Option<Error>,
+}
+
+impl AdjListChunkReader {
+    pub fn new(
+        graph_info: &GraphInfo,
+        src_type: &str,
+        edge_type: &str,
+        dst_type: &str,
+        adj_list_type: AdjListType,
+    ) -> Result<Self> {
+        let_cxx_string!(src_cxx = src_type);
+        let_cxx_string!(edge_cxx = edge_type);
+        let_cxx_string!(dst_cxx = dst_type);
+        let inner = graphar::new_adj_list_chunk_reader(
+            &graph_info.inner,
+            &src_cxx,
+            &edge_cxx,
+            &dst_cxx,
+            adj_list_type,
+        )?;
+        Ok(Self {
+            inner,
+            done: false,
+            error: None,
+        })
+    }
+
+    /// Moves to the edge at `offset` within the current vertex chunk.
+    pub fn seek(&mut self, offset: i64) -> Result<()> {
+        unsafe { graphar::adj_list_chunk_reader_seek(self.inner.pin_mut_unchecked(), offset)? };
+        self.done = false;
+        self.error = None;
+        Ok(())
+    }
+
+    /// Moves to the first edge of source vertex `id`.
+    pub fn seek_src(&mut self, id: i64) -> Result<()> {
+        unsafe { graphar::adj_list_chunk_reader_seek_src(self.inner.pin_mut_unchecked(), id)? };
+        self.done = false;
+        self.error = None;
+        Ok(())
+    }
+
+    /// Moves to the first edge of destination vertex `id`.
+    pub fn seek_dst(&mut self, id: i64) -> Result<()> {
+        unsafe { graphar::adj_list_chunk_reader_seek_dst(self.inner.pin_mut_unchecked(), id)? };
+        self.done = false;
+        self.error = None;
+        Ok(())
+    }
+
+    /// Reads the current chunk.
+    pub fn chunk(&mut self) -> Result<RecordBatch> {
+        let reader = unsafe { self.inner.pin_mut_unchecked() };
+        import_batch(reader, graphar::adj_list_chunk_reader_export)
+    }
+
+    /// Moves to the next chunk, returning `false` past the last one.
+    pub fn next_chunk(&mut self) -> Result<bool> {
+        Ok(unsafe { graphar::adj_list_chunk_reader_next_chunk(self.inner.pin_mut_unchecked())? })
+    }
+}
+
+impl Iterator for AdjListChunkReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.error.take() {
+            self.done = true;
+            return Some(Err(err));
+        }
+        if self.done {
+            return None;
+        }
+        let batch = self.chunk();
+        if batch.is_err() {
+            self.done = true;
+        } else {
+            match self.next_chunk() {
+                Ok(more) => self.done = !more,
+                Err(err) => self.error = Some(err),
+            }
+        }
+        Some(batch)
+    }
+}
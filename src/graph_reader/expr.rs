@@ -0,0 +1,219 @@
+//! Filter expressions for property-filtered vertex collections.
+//!
+//! ```ignore
+//! use graphar::graph_reader::{col, lit};
+//!
+//! let adults = col("age").ge(18).and(col("gender").eq("female"));
+//! let minors = col("age").lt(lit(18)) & !col("gender").eq("male");
+//! ```
+
+use std::ops::{BitAnd, BitOr, Not};
+
+use cxx::{SharedPtr, let_cxx_string};
+
+use crate::{
+    ffi::graphar,
+    graph_info::Property,
+    types::{Date, Timestamp},
+};
+
+/// A GraphAr filter expression.
+#[derive(Clone)]
+pub struct Expr {
+    inner: SharedPtr<graphar::Expression>,
+}
+
+/// References the property `name`.
+pub fn col(name: &str) -> Expr {
+    let_cxx_string!(name = name);
+    Expr {
+        inner: graphar::expression_property(&name),
+    }
+}
+
+/// Wraps `value` as a literal.
+pub fn lit<T: Literal>(value: T) -> Expr {
+    value.into_expr()
+}
+
+/// Values that can be used as literals in an [`Expr`].
+pub trait Literal {
+    fn into_expr(self) -> Expr;
+}
+
+impl Literal for bool {
+    fn into_expr(self) -> Expr {
+        Expr::new(graphar::expression_literal_bool(self))
+    }
+}
+
+impl Literal for i32 {
+    fn into_expr(self) -> Expr {
+        Expr::new(graphar::expression_literal_i32(self))
+    }
+}
+
+impl Literal for i64 {
+    fn into_expr(self) -> Expr {
+        Expr::new(graphar::expression_literal_i64(self))
+    }
+}
+
+impl Literal for f32 {
+    fn into_expr(self) -> Expr {
+        Expr::new(graphar::expression_literal_f32(self))
+    }
+}
+
+impl Literal for f64 {
+    fn into_expr(self) -> Expr {
+        Expr::new(graphar::expression_literal_f64(self))
+    }
+}
+
+impl Literal for &str {
+    fn into_expr(self) -> Expr {
+        let_cxx_string!(value = self);
+        Expr::new(graphar::expression_literal_string(&value))
+    }
+}
+
+impl Literal for String {
+    fn into_expr(self) -> Expr {
+        self.as_str().into_expr()
+    }
+}
+
+impl Literal for Date {
+    fn into_expr(self) -> Expr {
+        Expr::new(graphar::expression_literal_date(self.days()))
+    }
+}
+
+impl Literal for Timestamp {
+    fn into_expr(self) -> Expr {
+        Expr::new(graphar::expression_literal_timestamp(self.millis()))
+    }
+}
+
+impl<T: Literal> From<T> for Expr {
+    fn from(value: T) -> Self {
+        value.into_expr()
+    }
+}
+
+impl From<&Property> for Expr {
+    fn from(property: &Property) -> Self {
+        Expr::new(graphar::expression_property_by_property(&property.inner))
+    }
+}
+
+impl From<Expr> for SharedPtr<graphar::Expression> {
+    fn from(expr: Expr) -> Self {
+        expr.inner
+    }
+}
+
+impl Expr {
+    fn new(inner: SharedPtr<graphar::Expression>) -> Self {
+        Self { inner }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn eq(self, rhs: impl Into<Expr>) -> Expr {
+        Expr::new(graphar::expression_equal(&self.inner, &rhs.into().inner))
+    }
+
+    pub fn ne(self, rhs: impl Into<Expr>) -> Expr {
+        Expr::new(graphar::expression_not_equal(
+            &self.inner,
+            &rhs.into().inner,
+        ))
+    }
+
+    pub fn gt(self, rhs: impl Into<Expr>) -> Expr {
+        Expr::new(graphar::expression_greater_than(
+            &self.inner,
+            &rhs.into().inner,
+        ))
+    }
+
+    pub fn ge(self, rhs: impl Into<Expr>) -> Expr {
+        Expr::new(graphar::expression_greater_equal(
+            &self.inner,
+            &rhs.into().inner,
+        ))
+    }
+
+    pub fn lt(self, rhs: impl Into<Expr>) -> Expr {
+        Expr::new(graphar::expression_less_than(
+            &self.inner,
+            &rhs.into().inner,
+        ))
+    }
+
+    pub fn le(self, rhs: impl Into<Expr>) -> Expr {
+        Expr::new(graphar::expression_less_equal(
+            &self.inner,
+            &rhs.into().inner,
+        ))
+    }
+
+    pub fn and(self, rhs: Expr) -> Expr {
+        Expr::new(graphar::expression_and(&self.inner, &rhs.inner))
+    }
+
+    pub fn or(self, rhs: Expr) -> Expr {
+        Expr::new(graphar::expression_or(&self.inner, &rhs.inner))
+    }
+}
+
+impl Not for Expr {
+    type Output = Expr;
+
+    fn not(self) -> Expr {
+        Expr::new(graphar::expression_not(&self.inner))
+    }
+}
+
+impl BitAnd for Expr {
+    type Output = Expr;
+
+    fn bitand(self, rhs: Expr) -> Expr {
+        self.and(rhs)
+    }
+}
+
+impl BitOr for Expr {
+    type Output = Expr;
+
+    fn bitor(self, rhs: Expr) -> Expr {
+        self.or(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expr_builds_for_all_literals() {
+        let exprs = [
+            col("flag").eq(true),
+            col("age").gt(30_i32),
+            col("id").le(10_i64),
+            col("score").lt(0.5_f32),
+            col("rating").ge(4.5_f64),
+            col("name").ne("bob"),
+            col("name").eq(String::from("alice")),
+            col("birthday").lt(Date::from_days(0)),
+            col("created").ge(Timestamp::from_millis(0)),
+            !col("flag").eq(false),
+            col("age").gt(18) & col("age").lt(lit(65)),
+            col("age").lt(18) | col("age").gt(65),
+        ];
+        for expr in exprs {
+            assert!(!SharedPtr::from(expr).is_null());
+        }
+    }
+}
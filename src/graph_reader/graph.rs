@@ -0,0 +1,114 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    path::Path,
+};
+
+use super::{
+    Edges, Vertices,
+    neighbors::{EdgeTriple, NeighborReader, edge_info},
+};
+use crate::{
+    Error, Result,
+    graph_info::{AdjListType, GraphInfo},
+};
+
+// Ordered lists first, as they carry offsets; source-partitioned first, as
+// that is GraphAr's default layout.
+const ADJ_LIST_PREFERENCE: [AdjListType; 4] = [
+    AdjListType::OrderedBySource,
+    AdjListType::OrderedByDest,
+    AdjListType::UnorderedBySource,
+    AdjListType::UnorderedByDest,
+];
+
+pub(super) fn preferred_adj_list_type(
+    graph_info: &GraphInfo,
+    triple: &EdgeTriple,
+) -> Result<AdjListType> {
+    let info = edge_info(graph_info, triple)?;
+    ADJ_LIST_PREFERENCE
+        .into_iter()
+        .find(|&ty| info.has_adjacent_list_type(ty))
+        .ok_or_else(|| Error::key(format!("edge type {triple} has no adjacency list")))
+}
+
+/// A whole graph, opened from its `.graph.yml`.
+///
+/// Vertex and edge collections are opened on first use and cached.
+pub struct Graph {
+    info: GraphInfo,
+    vertices: HashMap<String, Vertices>,
+    edges: HashMap<EdgeTriple, Edges>,
+    neighbors: NeighborReader,
+}
+
+impl Graph {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self::new(GraphInfo::load(path)?))
+    }
+
+    pub fn new(info: GraphInfo) -> Self {
+        Self {
+            neighbors: NeighborReader::new(&info),
+            info,
+            vertices: HashMap::new(),
+            edges: HashMap::new(),
+        }
+    }
+
+    pub fn info(&self) -> &GraphInfo {
+        &self.info
+    }
+
+    pub fn vertex_types(&self) -> Vec<String> {
+        self.info
+            .vertex_infos()
+            .iter()
+            .map(|info| info.ty())
+            .collect()
+    }
+
+    pub fn edge_triples(&self) -> Vec<EdgeTriple> {
+        self.info
+            .edge_infos()
+            .iter()
+            .map(|info| EdgeTriple::new(info.src_type(), info.edge_type(), info.dst_type()))
+            .collect()
+    }
+
+    pub fn vertices(&mut self, ty: &str) -> Result<&mut Vertices> {
+        Ok(match self.vertices.entry(ty.to_string()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Vertices::new(&self.info, ty)?),
+        })
+    }
+
+    /// Opens the edges through the adjacency list picked by
+    /// [`Graph::adj_list_type`].
+    pub fn edges(&mut self, src_type: &str, edge_type: &str, dst_type: &str) -> Result<&mut Edges> {
+        let triple = EdgeTriple::new(src_type, edge_type, dst_type);
+        let adj_list_type = self.adj_list_type(&triple)?;
+        Ok(match self.edges.entry(triple) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Edges::new(
+                &self.info,
+                src_type,
+                edge_type,
+                dst_type,
+                adj_list_type,
+                None,
+            )?),
+        })
+    }
+
+    /// The adjacency list [`Graph::edges`] reads: ordered by source, ordered
+    /// by destination, unordered by source or unordered by destination, in
+    /// that order of preference.
+    pub fn adj_list_type(&self, triple: &EdgeTriple) -> Result<AdjListType> {
+        preferred_adj_list_type(&self.info, triple)
+    }
+
+    pub fn neighbors(&mut self) -> &mut NeighborReader {
+        &mut self.neighbors
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    collections::{HashMap, HashSet, hash_map::Entry},
+    fmt::Display,
+};
+
+use super::{Adjacency, Edge, Edges};
+use crate::{
+    Error, Result,
+    graph_info::{AdjListType, EdgeInfo, GraphInfo},
+};
+
+/// Identifies an edge type by its source, edge and destination types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeTriple {
+    pub src_type: String,
+    pub edge_type: String,
+    pub dst_type: String,
+}
+
+impl EdgeTriple {
+    pub fn new(
+        src_type: impl Into<String>,
+        edge_type: impl Into<String>,
+        dst_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            src_type: src_type.into(),
+            edge_type: edge_type.into(),
+            dst_type: dst_type.into(),
+        }
+    }
+}
+
+impl Display for EdgeTriple {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}_{}", self.src_type, self.edge_type, self.dst_type)
+    }
+}
+
+pub(super) fn edge_info(graph_info: &GraphInfo, triple: &EdgeTriple) -> Result<EdgeInfo> {
+    graph_info
+        .edge_info(&triple.src_type, &triple.edge_type, &triple.dst_type)
+        .ok_or_else(|| {
+            Error::key(format!(
+                "edge type {triple} not found in graph {}",
+                graph_info.name()
+            ))
+        })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    Out,
+    In,
+}
+
+impl Direction {
+    // The adjacency lists partitioned by this side of the edge.
+    fn adj_list_types(self) -> (AdjListType, AdjListType) {
+        match self {
+            Direction::Out => (AdjListType::OrderedBySource, AdjListType::UnorderedBySource),
+            Direction::In => (AdjListType::OrderedByDest, AdjListType::UnorderedByDest),
+        }
+    }
+
+    fn vertex_chunk_size(self, edge_info: &EdgeInfo) -> i64 {
+        match self {
+            Direction::Out => edge_info.src_chunk_size(),
+            Direction::In => edge_info.dst_chunk_size(),
+        }
+    }
+
+    fn key(self, edge: &Edge) -> i64 {
+        match self {
+            Direction::Out => edge.src(),
+            Direction::In => edge.dst(),
+        }
+    }
+
+    fn neighbor(self, edge: &Edge) -> i64 {
+        match self {
+            Direction::Out => edge.dst(),
+            Direction::In => edge.src(),
+        }
+    }
+
+    fn opposite(self) -> Self {
+        match self {
+            Direction::Out => Direction::In,
+            Direction::In => Direction::Out,
+        }
+    }
+}
+
+/// Neighbor and degree queries by vertex id.
+///
+/// Each query uses the best adjacency list the edge type has: the ordered
+/// list partitioned by the queried side (through a cached [`Adjacency`]),
+/// then the unordered one, then a full scan of a list partitioned by the
+/// other side. The scans log a warning the first time they are used for an
+/// edge type and direction.
+pub struct NeighborReader {
+    graph_info: GraphInfo,
+    adjacency: HashMap<(EdgeTriple, Direction), Adjacency>,
+    warned: HashSet<(EdgeTriple, Direction)>,
+}
+
+impl NeighborReader {
+    pub fn new(graph_info: &GraphInfo) -> Self {
+        Self {
+            graph_info: graph_info.clone(),
+            adjacency: HashMap::new(),
+            warned: HashSet::new(),
+        }
+    }
+
+    pub fn out_neighbors(&mut self, triple: &EdgeTriple, id: i64) -> Result<Vec<i64>> {
+        self.neighbors(triple, id, Direction::Out)
+    }
+
+    pub fn in_neighbors(&mut self, triple: &EdgeTriple, id: i64) -> Result<Vec<i64>> {
+        self.neighbors(triple, id, Direction::In)
+    }
+
+    pub fn out_degree(&mut self, triple: &EdgeTriple, id: i64) -> Result<usize> {
+        self.degree(triple, id, Direction::Out)
+    }
+
+    pub fn in_degree(&mut self, triple: &EdgeTriple, id: i64) -> Result<usize> {
+        self.degree(triple, id, Direction::In)
+    }
+
+    fn neighbors(&mut self, triple: &EdgeTriple, id: i64, dir: Direction) -> Result<Vec<i64>> {
+        let edge_info = edge_info(&self.graph_info, triple)?;
+        let (ordered, _) = dir.adj_list_types();
+        if !edge_info.has_adjacent_list_type(ordered) {
+            return self.scan(triple, &edge_info, id, dir);
+        }
+
+        let degree = self.adjacency(triple, dir)?.degree(id).unwrap_or(0);
+        if degree == 0 {
+            return Ok(Vec::new());
+        }
+        let chunk = id / dir.vertex_chunk_size(&edge_info);
+        let edges = self.edges(triple, ordered, Some(chunk..chunk + 1))?;
+        let begin = edges.cursor();
+        let found = match dir {
+            Direction::Out => edges.find_src(id, &begin),
+            Direction::In => edges.find_dst(id, &begin),
+        };
+        Ok(found.take(degree).map(|edge| dir.neighbor(&edge)).collect())
+    }
+
+    fn degree(&mut self, triple: &EdgeTriple, id: i64, dir: Direction) -> Result<usize> {
+        let edge_info = edge_info(&self.graph_info, triple)?;
+        let (ordered, _) = dir.adj_list_types();
+        if edge_info.has_adjacent_list_type(ordered) {
+            Ok(self.adjacency(triple, dir)?.degree(id).unwrap_or(0))
+        } else {
+            Ok(self.scan(triple, &edge_info, id, dir)?.len())
+        }
+    }
+
+    // Neighbors of `id` without offsets: the unordered list partitioned by
+    // the same side only needs the vertex chunk holding `id`, a list
+    // partitioned by the other side needs every edge.
+    fn scan(
+        &mut self,
+        triple: &EdgeTriple,
+        edge_info: &EdgeInfo,
+        id: i64,
+        dir: Direction,
+    ) -> Result<Vec<i64>> {
+        let (_, unordered) = dir.adj_list_types();
+        let (other_ordered, other_unordered) = dir.opposite().adj_list_types();
+        let (adj_list_type, vertex_chunks) = if edge_info.has_adjacent_list_type(unordered) {
+            let chunk = id / dir.vertex_chunk_size(edge_info);
+            (unordered, Some(chunk..chunk + 1))
+        } else if edge_info.has_adjacent_list_type(other_ordered) {
+            (other_ordered, None)
+        } else if edge_info.has_adjacent_list_type(other_unordered) {
+            (other_unordered, None)
+        } else {
+            return Err(Error::key(format!(
+                "edge type {triple} has no adjacency list"
+            )));
+        };
+
+        if self.warned.insert((triple.clone(), dir)) {
+            log::warn!(
+                "edge type {triple} has no offsets for {dir:?} neighbor queries; \
+                 scanning its {adj_list_type:?} adjacency list instead, which is slow"
+            );
+        }
+
+        let edges = self.edges(triple, adj_list_type, vertex_chunks)?;
+        Ok(edges
+            .into_iter()
+            .filter(|edge| dir.key(edge) == id)
+            .map(|edge| dir.neighbor(&edge))
+            .collect())
+    }
+
+    fn edges(
+        &self,
+        triple: &EdgeTriple,
+        adj_list_type: AdjListType,
+        vertex_chunks: Option<std::ops::Range<i64>>,
+    ) -> Result<Edges> {
+        Edges::new(
+            &self.graph_info,
+            &triple.src_type,
+            &triple.edge_type,
+            &triple.dst_type,
+            adj_list_type,
+            vertex_chunks,
+        )
+    }
+
+    fn adjacency(&mut self, triple: &EdgeTriple, dir: Direction) -> Result<&Adjacency> {
+        Ok(match self.adjacency.entry((triple.clone(), dir)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Adjacency::new(
+                &self.graph_info,
+                &triple.src_type,
+                &triple.edge_type,
+                &triple.dst_type,
+                dir.adj_list_types().0,
+            )?),
+        })
+    }
+}
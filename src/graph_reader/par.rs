@@ -0,0 +1,103 @@
+//! Chunk-parallel iteration on the rayon global pool. Requires the `rayon`
+//! feature.
+
+use std::iter::Take;
+
+use cxx::let_cxx_string;
+use rayon::prelude::*;
+
+use super::{EdgeIter, Edges, Selection, VertexIter, Vertices, vertex_iter};
+use crate::{Result, ffi::graphar};
+
+// One vertex chunk of a collection: a contiguous id range of an unfiltered
+// collection, or the matching ids of a filtered one.
+enum VertexPart {
+    Range { start: i64, len: usize },
+    Ids(Vec<i64>),
+}
+
+impl Vertices {
+    /// Splits the collection at GraphAr's vertex chunks and yields one
+    /// iterator per chunk, to be consumed in parallel.
+    ///
+    /// Filtered collections first collect their ids sequentially, then read
+    /// the chunks holding matches in parallel.
+    pub fn par_chunks(&self) -> impl IndexedParallelIterator<Item = Result<Take<VertexIter>>> + '_ {
+        let chunk_size = self.chunk_size;
+        let parts: Vec<VertexPart> = if matches!(self.selection, Selection::All) {
+            let len = self.len() as i64;
+            (0..len)
+                .step_by(chunk_size as usize)
+                .map(|start| VertexPart::Range {
+                    start,
+                    len: chunk_size.min(len - start) as usize,
+                })
+                .collect()
+        } else {
+            self.collect_ids()
+                .chunk_by(|a, b| a / chunk_size == b / chunk_size)
+                .map(|ids| VertexPart::Ids(ids.to_vec()))
+                .collect()
+        };
+
+        parts.into_par_iter().map(move |part| match part {
+            VertexPart::Range { start, len } => Ok(self.find(start).take(len)),
+            VertexPart::Ids(ids) => {
+                let_cxx_string!(ty_cxx = &self.ty);
+                let mut inner =
+                    graphar::vertices_collection_with_ids(&self.graph_info, &ty_cxx, &ids)?;
+                let iter = vertex_iter(
+                    &mut inner,
+                    self.schema.clone(),
+                    graphar::vertices_collection_begin,
+                );
+                Ok(iter.take(ids.len()))
+            }
+        })
+    }
+
+    // Sorted ids of the vertices in this collection.
+    fn collect_ids(&self) -> Vec<i64> {
+        let mut ids = Vec::with_capacity(self.len());
+        let mut iter = self.begin();
+        while !graphar::vertex_iter_eq(&iter.iter, &iter.end) {
+            ids.push(iter.id());
+            iter.advance();
+        }
+        ids.sort_unstable();
+        ids
+    }
+}
+
+impl Edges {
+    /// Splits the collection at the vertex chunks of its adjacency list and
+    /// yields one iterator per chunk, to be consumed in parallel.
+    pub fn par_chunks(&self) -> Result<impl IndexedParallelIterator<Item = Result<EdgeIter>> + '_> {
+        let triple = &self.triple;
+        let_cxx_string!(src_cxx = &triple.src_type);
+        let_cxx_string!(edge_cxx = &triple.edge_type);
+        let_cxx_string!(dst_cxx = &triple.dst_type);
+        let chunk_num = graphar::edges_vertex_chunk_num(
+            &self.graph_info.inner,
+            &src_cxx,
+            &edge_cxx,
+            &dst_cxx,
+            self.adj_list_type,
+        )?;
+
+        // rayon cannot index a `Range<i64>`.
+        let chunks: Vec<i64> =
+            (self.vertex_chunks.start.max(0)..self.vertex_chunks.end.min(chunk_num)).collect();
+        Ok(chunks.into_par_iter().map(move |chunk| {
+            let edges = Edges::new(
+                &self.graph_info,
+                &triple.src_type,
+                &triple.edge_type,
+                &triple.dst_type,
+                self.adj_list_type,
+                Some(chunk..chunk + 1),
+            )?;
+            Ok(edges.begin())
+        }))
+    }
+}
@@ -2,6 +2,14 @@
 #include "graphar/fwd.h"
 #include "graphar/graph_info.h"
 #include "graphar/high-level/graph_reader.h"
+#include "graphar/reader_util.h"
+
+#include "arrow/array.h"
+#include "arrow/c/bridge.h"
+#include "arrow/compute/expression.h"
+#include "arrow/record_batch.h"
+#include "arrow/scalar.h"
+#include "arrow/type.h"
 
 #include <algorithm>
 #include <cassert>
@@ -9,27 +17,85 @@
 #include <memory>
 #include <stdexcept>
 #include <string>
+#include <unordered_map>
 #include <utility>
 #include <vector>
 
-// Should we replace std::runtime_error with custom exception??
 namespace graphar_rs {
 
 namespace {
 
+// cxx hands exceptions to Rust with only their `what()`, so the status code
+// travels as a `[<code>] ` prefix, parsed back by `graphar::Error`.
+std::string StatusCodeName(const graphar::Status &status) {
+  if (status.IsKeyError()) {
+    return "KeyError";
+  } else if (status.IsTypeError()) {
+    return "TypeError";
+  } else if (status.IsInvalid()) {
+    return "Invalid";
+  } else if (status.IsIndexError()) {
+    return "IndexError";
+  } else if (status.IsOutOfMemory()) {
+    return "OutOfMemory";
+  } else if (status.IsIOError()) {
+    return "IOError";
+  } else if (status.IsYamlError()) {
+    return "YamlError";
+  } else if (status.IsArrowError()) {
+    return "ArrowError";
+  }
+  return "UnknownError";
+}
+
+[[noreturn]] void ThrowStatus(const graphar::Status &status) {
+  throw std::runtime_error("[" + StatusCodeName(status) + "] " +
+                           status.message());
+}
+
+[[noreturn]] void ThrowKeyError(const std::string &message) {
+  throw std::runtime_error("[KeyError] " + message);
+}
+
 template <typename T> T ValueOrThrow(graphar::Result<T> &&result) {
   if (result.has_error()) {
-    throw std::runtime_error(result.error().message());
+    ThrowStatus(result.error());
   }
   return std::move(result).value();
 }
 
 inline void ThrowIfStatusError(const graphar::Status &status) {
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 
+inline void ThrowIfArrowError(const arrow::Status &status) {
+  if (!status.ok()) {
+    throw std::runtime_error("[ArrowError] " + status.ToString());
+  }
+}
+
+// Returns false once the reader has moved past its last chunk.
+inline bool NextChunkOrThrow(const graphar::Status &status) {
+  if (status.IsIndexError()) {
+    return false;
+  }
+  ThrowIfStatusError(status);
+  return true;
+}
+
+// Exports a chunk through the arrow C data interface. Single-batch tables,
+// which is what GraphAr reads per chunk, are handed over without copying.
+void ExportTable(const std::shared_ptr<arrow::Table> &table, size_t out_array,
+                 size_t out_schema) {
+  auto batch = table->CombineChunksToBatch();
+  ThrowIfArrowError(batch.status());
+  ThrowIfArrowError(arrow::ExportRecordBatch(
+      **batch, reinterpret_cast<struct ArrowArray *>(out_array),
+      reinterpret_cast<struct ArrowSchema *>(out_schema)));
+}
+
 template <typename T>
 T EdgePropertyOrThrow(graphar::EdgeIter &iter, const std::string &name) {
   auto edge = (*iter);
@@ -44,6 +110,64 @@ T VertexPropertyOrThrow(graphar::VertexIter &iter, const std::string &name) {
   return ValueOrThrow(std::move(result));
 }
 
+template <typename T> std::vector<T> SliceToVector(rust::Slice<const T> slice) {
+  return std::vector<T>(slice.begin(), slice.end());
+}
+
+template <typename T>
+void AddMultiProperty(graphar::builder::Vertex &v, const std::string &name,
+                      graphar::Cardinality cardinality,
+                      rust::Slice<const T> values) {
+  for (auto const &value : values) {
+    v.AddProperty(cardinality, name, value);
+  }
+}
+
+std::vector<std::string> StringsToVector(const rust::Vec<rust::String> &vec) {
+  std::vector<std::string> out;
+  out.reserve(vec.size());
+  for (auto const &item : vec) {
+    out.emplace_back(std::string(item));
+  }
+  return out;
+}
+
+// `graphar::*Array` are views over the arrow list values of a property.
+template <typename T, typename Array>
+rust::Vec<T> ArrayToVec(const Array &array) {
+  rust::Vec<T> out;
+  out.reserve(array.size());
+  for (size_t i = 0; i < static_cast<size_t>(array.size()); ++i) {
+    out.push_back(static_cast<T>(array[i]));
+  }
+  return out;
+}
+
+rust::Vec<rust::String> StringArrayToVec(const graphar::StringArray &array) {
+  rust::Vec<rust::String> out;
+  out.reserve(array.size());
+  for (size_t i = 0; i < static_cast<size_t>(array.size()); ++i) {
+    auto value = array[i];
+    out.push_back(rust::String(value.data(), value.size()));
+  }
+  return out;
+}
+
+// `graphar::_Literal` only accepts C types that arrow can turn into a scalar
+// directly, so date and timestamp literals carry their arrow scalar instead.
+class ScalarLiteral : public graphar::Expression {
+public:
+  explicit ScalarLiteral(std::shared_ptr<arrow::Scalar> scalar)
+      : scalar_(std::move(scalar)) {}
+
+  graphar::Result<arrow::compute::Expression> Evaluate() override {
+    return arrow::compute::literal(arrow::Datum(scalar_));
+  }
+
+private:
+  std::shared_ptr<arrow::Scalar> scalar_;
+};
+
 } // namespace
 
 rust::String to_type_name(const graphar::DataType &type) {
@@ -62,7 +186,7 @@ new_const_info_version(int version) {
 std::shared_ptr<graphar::GraphInfo> load_graph_info(const std::string &path) {
   auto r = graphar::GraphInfo::Load(path);
   if (!r) {
-    throw std::runtime_error(r.error().message());
+    ThrowStatus(r.error());
   }
   return std::move(r).value();
 }
@@ -85,18 +209,54 @@ void graph_info_save(const graphar::GraphInfo &graph_info,
                      const std::string &path) {
   auto status = graph_info.Save(path);
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 std::unique_ptr<std::string>
 graph_info_dump(const graphar::GraphInfo &graph_info) {
   auto r = graph_info.Dump();
   if (!r) {
-    throw std::runtime_error(r.error().message());
+    ThrowStatus(r.error());
   }
   return std::make_unique<std::string>(std::move(r).value());
 }
 
+// Extra info crosses the bridge as alternating keys and values.
+rust::Vec<rust::String>
+graph_info_extra_info(const graphar::GraphInfo &graph_info) {
+  rust::Vec<rust::String> out;
+  for (const auto &[key, value] : graph_info.GetExtraInfo()) {
+    out.push_back(rust::String(key));
+    out.push_back(rust::String(value));
+  }
+  return out;
+}
+
+std::shared_ptr<graphar::GraphInfo>
+graph_info_with_extra_info(const graphar::GraphInfo &graph_info,
+                           const rust::Vec<rust::String> &extra_info) {
+  std::unordered_map<std::string, std::string> extra;
+  for (size_t i = 0; i + 1 < extra_info.size(); i += 2) {
+    extra.emplace(std::string(extra_info[i]), std::string(extra_info[i + 1]));
+  }
+  return graphar::CreateGraphInfo(
+      graph_info.GetName(), graph_info.GetVertexInfos(),
+      graph_info.GetEdgeInfos(), graph_info.GetLabels(),
+      graph_info.GetPrefix(), graph_info.version(), extra);
+}
+
+std::shared_ptr<graphar::GraphInfo>
+graph_info_add_vertex(const graphar::GraphInfo &graph_info,
+                      const std::shared_ptr<graphar::VertexInfo> &vertex_info) {
+  return ValueOrThrow(graph_info.AddVertex(vertex_info));
+}
+
+std::shared_ptr<graphar::GraphInfo>
+graph_info_add_edge(const graphar::GraphInfo &graph_info,
+                    const std::shared_ptr<graphar::EdgeInfo> &edge_info) {
+  return ValueOrThrow(graph_info.AddEdge(edge_info));
+}
+
 std::unique_ptr<graphar::Property>
 new_property(const std::string &name,
              const std::shared_ptr<graphar::DataType> &type, bool is_primary,
@@ -145,18 +305,48 @@ void push_property_group(
 void vertex_info_save(const graphar::VertexInfo &v, const std::string &path) {
   auto status = v.Save(path);
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 
 std::unique_ptr<std::string> vertex_info_dump(const graphar::VertexInfo &v) {
   auto r = v.Dump();
   if (!r) {
-    throw std::runtime_error(r.error().message());
+    ThrowStatus(r.error());
   }
   return std::make_unique<std::string>(std::move(r).value());
 }
 
+std::shared_ptr<graphar::VertexInfo> vertex_info_add_property_group(
+    const graphar::VertexInfo &v,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group) {
+  return ValueOrThrow(v.AddPropertyGroup(property_group));
+}
+
+rust::String vertex_info_file_path(
+    const graphar::VertexInfo &v,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group,
+    graphar::IdType chunk_index) {
+  return rust::String(
+      ValueOrThrow(v.GetFilePath(property_group, chunk_index)));
+}
+
+rust::String vertex_info_path_prefix(
+    const graphar::VertexInfo &v,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group) {
+  return rust::String(ValueOrThrow(v.GetPathPrefix(property_group)));
+}
+
+rust::String vertex_info_vertices_num_file_path(const graphar::VertexInfo &v) {
+  return rust::String(ValueOrThrow(v.GetVerticesNumFilePath()));
+}
+
+graphar::IdType
+vertex_info_vertices_num(const std::string &prefix,
+                         const std::shared_ptr<graphar::VertexInfo> &v) {
+  return ValueOrThrow(graphar::util::GetVertexNum(prefix, v));
+}
+
 std::shared_ptr<graphar::VertexInfo>
 create_vertex_info(const rust::String &type, graphar::IdType chunk_size,
                    const graphar::PropertyGroupVector &property_group,
@@ -204,6 +394,71 @@ void vertex_add_property_string(graphar::builder::Vertex &v,
                                 const std::string &val) {
   v.AddProperty(name, val);
 }
+void vertex_add_property_date(graphar::builder::Vertex &v,
+                              const std::string &name, int32_t days) {
+  v.AddProperty(name, graphar::Date(days));
+}
+void vertex_add_property_timestamp(graphar::builder::Vertex &v,
+                                   const std::string &name, int64_t millis) {
+  v.AddProperty(name, graphar::Timestamp(millis));
+}
+void vertex_add_property_list_i32(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const int32_t> val) {
+  v.AddProperty(name, SliceToVector(val));
+}
+void vertex_add_property_list_i64(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const int64_t> val) {
+  v.AddProperty(name, SliceToVector(val));
+}
+void vertex_add_property_list_f32(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const float> val) {
+  v.AddProperty(name, SliceToVector(val));
+}
+void vertex_add_property_list_f64(graphar::builder::Vertex &v,
+                                  const std::string &name,
+                                  rust::Slice<const double> val) {
+  v.AddProperty(name, SliceToVector(val));
+}
+void vertex_add_property_list_string(graphar::builder::Vertex &v,
+                                     const std::string &name,
+                                     const rust::Vec<rust::String> &val) {
+  v.AddProperty(name, StringsToVector(val));
+}
+void vertex_add_multi_property_i32(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const int32_t> val) {
+  AddMultiProperty(v, name, cardinality, val);
+}
+void vertex_add_multi_property_i64(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const int64_t> val) {
+  AddMultiProperty(v, name, cardinality, val);
+}
+void vertex_add_multi_property_f32(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const float> val) {
+  AddMultiProperty(v, name, cardinality, val);
+}
+void vertex_add_multi_property_f64(graphar::builder::Vertex &v,
+                                   const std::string &name,
+                                   graphar::Cardinality cardinality,
+                                   rust::Slice<const double> val) {
+  AddMultiProperty(v, name, cardinality, val);
+}
+void vertex_add_multi_property_string(graphar::builder::Vertex &v,
+                                      const std::string &name,
+                                      graphar::Cardinality cardinality,
+                                      const rust::Vec<rust::String> &val) {
+  for (auto const &value : val) {
+    v.AddProperty(cardinality, name, std::string(value));
+  }
+}
 
 std::shared_ptr<graphar::builder::VerticesBuilder>
 new_vertices_builder(const std::shared_ptr<graphar::VertexInfo> &vertex_info,
@@ -212,7 +467,7 @@ new_vertices_builder(const std::shared_ptr<graphar::VertexInfo> &vertex_info,
   auto r = graphar::builder::VerticesBuilder::Make(vertex_info, path_prefix,
                                                    start_index);
   if (!r) {
-    throw std::runtime_error(r.error().message());
+    ThrowStatus(r.error());
   }
   return std::move(r).value();
 }
@@ -221,14 +476,14 @@ void add_vertex(graphar::builder::VerticesBuilder &builder,
                 graphar::builder::Vertex &v) {
   auto status = builder.AddVertex(v);
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 
 void vertices_dump(graphar::builder::VerticesBuilder &builder) {
   auto status = builder.Dump();
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 
@@ -244,18 +499,107 @@ void edge_info_save(const graphar::EdgeInfo &edge_info,
                     const std::string &path) {
   auto status = edge_info.Save(path);
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 std::unique_ptr<std::string>
 edge_info_dump(const graphar::EdgeInfo &edge_info) {
   auto r = edge_info.Dump();
   if (!r) {
-    throw std::runtime_error(r.error().message());
+    ThrowStatus(r.error());
   }
   return std::make_unique<std::string>(std::move(r).value());
 }
 
+std::shared_ptr<graphar::EdgeInfo> edge_info_add_property_group(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group) {
+  return ValueOrThrow(edge_info.AddPropertyGroup(property_group));
+}
+
+std::shared_ptr<graphar::EdgeInfo> edge_info_add_adjacent_list(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::AdjacentList> &adj_list) {
+  return ValueOrThrow(edge_info.AddAdjacentList(adj_list));
+}
+
+rust::String
+edge_info_vertices_num_file_path(const graphar::EdgeInfo &edge_info,
+                                 graphar::AdjListType adj_list_type) {
+  return rust::String(
+      ValueOrThrow(edge_info.GetVerticesNumFilePath(adj_list_type)));
+}
+
+rust::String edge_info_edges_num_file_path(const graphar::EdgeInfo &edge_info,
+                                           graphar::IdType vertex_chunk_index,
+                                           graphar::AdjListType adj_list_type) {
+  return rust::String(ValueOrThrow(
+      edge_info.GetEdgesNumFilePath(vertex_chunk_index, adj_list_type)));
+}
+
+rust::String edge_info_adj_list_file_path(const graphar::EdgeInfo &edge_info,
+                                          graphar::IdType vertex_chunk_index,
+                                          graphar::IdType edge_chunk_index,
+                                          graphar::AdjListType adj_list_type) {
+  return rust::String(ValueOrThrow(edge_info.GetAdjListFilePath(
+      vertex_chunk_index, edge_chunk_index, adj_list_type)));
+}
+
+rust::String
+edge_info_adj_list_path_prefix(const graphar::EdgeInfo &edge_info,
+                               graphar::AdjListType adj_list_type) {
+  return rust::String(
+      ValueOrThrow(edge_info.GetAdjListPathPrefix(adj_list_type)));
+}
+
+rust::String
+edge_info_adj_list_offset_file_path(const graphar::EdgeInfo &edge_info,
+                                    graphar::IdType vertex_chunk_index,
+                                    graphar::AdjListType adj_list_type) {
+  return rust::String(ValueOrThrow(
+      edge_info.GetAdjListOffsetFilePath(vertex_chunk_index, adj_list_type)));
+}
+
+rust::String edge_info_offset_path_prefix(const graphar::EdgeInfo &edge_info,
+                                          graphar::AdjListType adj_list_type) {
+  return rust::String(
+      ValueOrThrow(edge_info.GetOffsetPathPrefix(adj_list_type)));
+}
+
+rust::String edge_info_property_file_path(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group,
+    graphar::AdjListType adj_list_type, graphar::IdType vertex_chunk_index,
+    graphar::IdType edge_chunk_index) {
+  return rust::String(ValueOrThrow(edge_info.GetPropertyFilePath(
+      property_group, adj_list_type, vertex_chunk_index, edge_chunk_index)));
+}
+
+rust::String edge_info_property_group_path_prefix(
+    const graphar::EdgeInfo &edge_info,
+    const std::shared_ptr<graphar::PropertyGroup> &property_group,
+    graphar::AdjListType adj_list_type) {
+  return rust::String(ValueOrThrow(
+      edge_info.GetPropertyGroupPathPrefix(property_group, adj_list_type)));
+}
+
+graphar::IdType
+edge_info_vertices_num(const std::string &prefix,
+                       const std::shared_ptr<graphar::EdgeInfo> &edge_info,
+                       graphar::AdjListType adj_list_type) {
+  return ValueOrThrow(
+      graphar::util::GetVertexNum(prefix, edge_info, adj_list_type));
+}
+
+graphar::IdType
+edge_info_edges_num(const std::string &prefix,
+                    const std::shared_ptr<graphar::EdgeInfo> &edge_info,
+                    graphar::AdjListType adj_list_type,
+                    graphar::IdType vertex_chunk_index) {
+  return ValueOrThrow(graphar::util::GetEdgeNum(
+      prefix, edge_info, adj_list_type, vertex_chunk_index));
+}
+
 std::unique_ptr<graphar::builder::Edge> new_edge(graphar::IdType src_id,
                                                  graphar::IdType dst_id) {
   return std::make_unique<graphar::builder::Edge>(src_id, dst_id);
@@ -285,6 +629,39 @@ void edge_add_property_string(graphar::builder::Edge &e,
                               const std::string &name, const std::string &val) {
   e.AddProperty(name, val);
 }
+void edge_add_property_date(graphar::builder::Edge &e, const std::string &name,
+                            int32_t days) {
+  e.AddProperty(name, graphar::Date(days));
+}
+void edge_add_property_timestamp(graphar::builder::Edge &e,
+                                 const std::string &name, int64_t millis) {
+  e.AddProperty(name, graphar::Timestamp(millis));
+}
+void edge_add_property_list_i32(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const int32_t> val) {
+  e.AddProperty(name, SliceToVector(val));
+}
+void edge_add_property_list_i64(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const int64_t> val) {
+  e.AddProperty(name, SliceToVector(val));
+}
+void edge_add_property_list_f32(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const float> val) {
+  e.AddProperty(name, SliceToVector(val));
+}
+void edge_add_property_list_f64(graphar::builder::Edge &e,
+                                const std::string &name,
+                                rust::Slice<const double> val) {
+  e.AddProperty(name, SliceToVector(val));
+}
+void edge_add_property_list_string(graphar::builder::Edge &e,
+                                   const std::string &name,
+                                   const rust::Vec<rust::String> &val) {
+  e.AddProperty(name, StringsToVector(val));
+}
 
 std::shared_ptr<graphar::builder::EdgesBuilder>
 new_edges_builder(const std::shared_ptr<graphar::EdgeInfo> &edge_info,
@@ -294,7 +671,7 @@ new_edges_builder(const std::shared_ptr<graphar::EdgeInfo> &edge_info,
   auto r = graphar::builder::EdgesBuilder::Make(edge_info, path_prefix,
                                                 adj_list_type, vertices_num);
   if (!r) {
-    throw std::runtime_error(r.error().message());
+    ThrowStatus(r.error());
   }
   return std::move(r).value();
 }
@@ -303,14 +680,14 @@ void add_edge(graphar::builder::EdgesBuilder &builder,
               graphar::builder::Edge &e) {
   auto status = builder.AddEdge(e);
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 
 void edges_dump(graphar::builder::EdgesBuilder &builder) {
   auto status = builder.Dump();
   if (!status.ok()) {
-    throw std::runtime_error(status.message());
+    ThrowStatus(status);
   }
 }
 
@@ -331,6 +708,33 @@ rust::String vertex_property_string(const graphar::Vertex &vertex,
                                     const std::string &name) {
   return rust::String(ValueOrThrow(vertex.property<std::string>(name)));
 }
+int32_t vertex_property_date(const graphar::Vertex &vertex,
+                             const std::string &name) {
+  return ValueOrThrow(vertex.property<graphar::Date>(name)).value();
+}
+
+int64_t vertex_property_timestamp(const graphar::Vertex &vertex,
+                                  const std::string &name) {
+  return ValueOrThrow(vertex.property<graphar::Timestamp>(name)).value();
+}
+
+#define DEF_VERTEX_LIST_PROPERTY_FUNC(type, array)                             \
+  rust::Vec<type> vertex_property_list_##type(const graphar::Vertex &vertex,   \
+                                              const std::string &name) {       \
+    return ArrayToVec<type>(ValueOrThrow(vertex.property<array>(name)));       \
+  }
+
+DEF_VERTEX_LIST_PROPERTY_FUNC(i32, graphar::Int32Array)
+DEF_VERTEX_LIST_PROPERTY_FUNC(i64, graphar::Int64Array)
+DEF_VERTEX_LIST_PROPERTY_FUNC(f32, graphar::FloatArray)
+DEF_VERTEX_LIST_PROPERTY_FUNC(f64, graphar::DoubleArray)
+
+rust::Vec<rust::String>
+vertex_property_list_string(const graphar::Vertex &vertex,
+                            const std::string &name) {
+  return StringArrayToVec(
+      ValueOrThrow(vertex.property<graphar::StringArray>(name)));
+}
 
 // Edge
 #define DEF_EDGE_PROPERTY_FUNC(type)                                           \
@@ -349,6 +753,31 @@ rust::String edge_property_string(const graphar::Edge &edge,
                                   const std::string &name) {
   return rust::String(ValueOrThrow(edge.property<std::string>(name)));
 }
+int32_t edge_property_date(const graphar::Edge &edge, const std::string &name) {
+  return ValueOrThrow(edge.property<graphar::Date>(name)).value();
+}
+
+int64_t edge_property_timestamp(const graphar::Edge &edge,
+                                const std::string &name) {
+  return ValueOrThrow(edge.property<graphar::Timestamp>(name)).value();
+}
+
+#define DEF_EDGE_LIST_PROPERTY_FUNC(type, array)                               \
+  rust::Vec<type> edge_property_list_##type(const graphar::Edge &edge,         \
+                                            const std::string &name) {         \
+    return ArrayToVec<type>(ValueOrThrow(edge.property<array>(name)));         \
+  }
+
+DEF_EDGE_LIST_PROPERTY_FUNC(i32, graphar::Int32Array)
+DEF_EDGE_LIST_PROPERTY_FUNC(i64, graphar::Int64Array)
+DEF_EDGE_LIST_PROPERTY_FUNC(f32, graphar::FloatArray)
+DEF_EDGE_LIST_PROPERTY_FUNC(f64, graphar::DoubleArray)
+
+rust::Vec<rust::String> edge_property_list_string(const graphar::Edge &edge,
+                                                  const std::string &name) {
+  return StringArrayToVec(
+      ValueOrThrow(edge.property<graphar::StringArray>(name)));
+}
 
 // VertexIter
 
@@ -356,6 +785,10 @@ std::unique_ptr<graphar::Vertex> vertex_iter_deref(graphar::VertexIter &iter) {
   return std::make_unique<graphar::Vertex>(*iter);
 }
 
+bool vertex_iter_is_valid(graphar::VertexIter &iter, const std::string &name) {
+  return (*iter).IsValid(name);
+}
+
 bool vertex_iter_eq(const std::unique_ptr<graphar::VertexIter> &lhs,
                     const std::unique_ptr<graphar::VertexIter> &rhs) {
   return *lhs == *rhs;
@@ -393,6 +826,47 @@ rust::String vertex_iter_property_string(graphar::VertexIter &iter,
   return rust::String(VertexPropertyOrThrow<std::string>(iter, name));
 }
 
+int32_t vertex_iter_property_date(graphar::VertexIter &iter,
+                                  const std::string &name) {
+  return VertexPropertyOrThrow<graphar::Date>(iter, name).value();
+}
+
+int64_t vertex_iter_property_timestamp(graphar::VertexIter &iter,
+                                       const std::string &name) {
+  return VertexPropertyOrThrow<graphar::Timestamp>(iter, name).value();
+}
+
+rust::Vec<int32_t> vertex_iter_property_list_i32(graphar::VertexIter &iter,
+                                                 const std::string &name) {
+  return ArrayToVec<int32_t>(
+      VertexPropertyOrThrow<graphar::Int32Array>(iter, name));
+}
+
+rust::Vec<int64_t> vertex_iter_property_list_i64(graphar::VertexIter &iter,
+                                                 const std::string &name) {
+  return ArrayToVec<int64_t>(
+      VertexPropertyOrThrow<graphar::Int64Array>(iter, name));
+}
+
+rust::Vec<float> vertex_iter_property_list_f32(graphar::VertexIter &iter,
+                                               const std::string &name) {
+  return ArrayToVec<float>(
+      VertexPropertyOrThrow<graphar::FloatArray>(iter, name));
+}
+
+rust::Vec<double> vertex_iter_property_list_f64(graphar::VertexIter &iter,
+                                                const std::string &name) {
+  return ArrayToVec<double>(
+      VertexPropertyOrThrow<graphar::DoubleArray>(iter, name));
+}
+
+rust::Vec<rust::String>
+vertex_iter_property_list_string(graphar::VertexIter &iter,
+                                 const std::string &name) {
+  return StringArrayToVec(
+      VertexPropertyOrThrow<graphar::StringArray>(iter, name));
+}
+
 bool vertex_iter_has_label(graphar::VertexIter &iter,
                            const std::string &label) {
   auto result = iter.hasLabel(label);
@@ -413,6 +887,10 @@ std::unique_ptr<graphar::Edge> edge_iter_deref(graphar::EdgeIter &iter) {
   return std::make_unique<graphar::Edge>(*iter);
 }
 
+bool edge_iter_is_valid(graphar::EdgeIter &iter, const std::string &name) {
+  return (*iter).IsValid(name);
+}
+
 bool edge_iter_eq(const std::unique_ptr<graphar::EdgeIter> &lhs,
                   const std::unique_ptr<graphar::EdgeIter> &rhs) {
   return *lhs == *rhs;
@@ -446,6 +924,45 @@ rust::String edge_iter_property_string(graphar::EdgeIter &iter,
   return rust::String(EdgePropertyOrThrow<std::string>(iter, name));
 }
 
+int32_t edge_iter_property_date(graphar::EdgeIter &iter,
+                                const std::string &name) {
+  return EdgePropertyOrThrow<graphar::Date>(iter, name).value();
+}
+
+int64_t edge_iter_property_timestamp(graphar::EdgeIter &iter,
+                                     const std::string &name) {
+  return EdgePropertyOrThrow<graphar::Timestamp>(iter, name).value();
+}
+
+rust::Vec<int32_t> edge_iter_property_list_i32(graphar::EdgeIter &iter,
+                                               const std::string &name) {
+  return ArrayToVec<int32_t>(
+      EdgePropertyOrThrow<graphar::Int32Array>(iter, name));
+}
+
+rust::Vec<int64_t> edge_iter_property_list_i64(graphar::EdgeIter &iter,
+                                               const std::string &name) {
+  return ArrayToVec<int64_t>(
+      EdgePropertyOrThrow<graphar::Int64Array>(iter, name));
+}
+
+rust::Vec<float> edge_iter_property_list_f32(graphar::EdgeIter &iter,
+                                             const std::string &name) {
+  return ArrayToVec<float>(EdgePropertyOrThrow<graphar::FloatArray>(iter, name));
+}
+
+rust::Vec<double> edge_iter_property_list_f64(graphar::EdgeIter &iter,
+                                              const std::string &name) {
+  return ArrayToVec<double>(
+      EdgePropertyOrThrow<graphar::DoubleArray>(iter, name));
+}
+
+rust::Vec<rust::String>
+edge_iter_property_list_string(graphar::EdgeIter &iter,
+                               const std::string &name) {
+  return StringArrayToVec(EdgePropertyOrThrow<graphar::StringArray>(iter, name));
+}
+
 void edge_iter_next(graphar::EdgeIter &iter) { ++iter; }
 
 bool edge_iter_next_src(graphar::EdgeIter &iter) { return iter.next_src(); }
@@ -545,6 +1062,46 @@ std::shared_ptr<graphar::VerticesCollection> vertices_collection_with_property(
       property_name, filter, graph_info, type));
 }
 
+std::shared_ptr<graphar::VerticesCollection>
+vertices_collection_with_ids(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                             const std::string &type,
+                             rust::Slice<const graphar::IdType> ids) {
+  auto vertex_info = graph_info->GetVertexInfo(type);
+  if (vertex_info == nullptr) {
+    ThrowKeyError("The vertex type " + type +
+                  " doesn't exist in the graph info");
+  }
+  return std::make_shared<graphar::VerticesCollection>(
+      vertex_info, graph_info->GetPrefix(), true, SliceToVector(ids));
+}
+
+std::shared_ptr<graphar::VerticesCollection>
+vertices_collection_refine_with_labels(
+    const std::shared_ptr<graphar::VerticesCollection> &vc,
+    const std::vector<std::string> &labels) {
+  return ValueOrThrow(
+      graphar::VerticesCollection::verticesWithMultipleLabels(labels, vc));
+}
+
+std::shared_ptr<graphar::VerticesCollection>
+vertices_collection_refine_with_property(
+    const std::shared_ptr<graphar::VerticesCollection> &vc,
+    const std::string &property_name,
+    const std::shared_ptr<graphar::Expression> &filter) {
+  return ValueOrThrow(graphar::VerticesCollection::verticesWithProperty(
+      property_name, filter, vc));
+}
+
+std::unique_ptr<std::vector<std::string>>
+new_string_vector(rust::Slice<const rust::Str> values) {
+  auto out = std::make_unique<std::vector<std::string>>();
+  out->reserve(values.size());
+  for (auto const &value : values) {
+    out->emplace_back(std::string(value));
+  }
+  return out;
+}
+
 // EdgesCollection helpers
 std::unique_ptr<graphar::EdgeIter>
 edges_collection_begin(graphar::EdgesCollection &collection) {
@@ -578,11 +1135,30 @@ std::shared_ptr<graphar::EdgesCollection> edges_collection_make(
       vertex_chunk_begin, vertex_chunk_end));
 }
 
+graphar::IdType
+edges_vertex_chunk_num(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                       const std::string &src_type, const std::string &edge_type,
+                       const std::string &dst_type,
+                       graphar::AdjListType adj_list_type) {
+  auto edge_info = graph_info->GetEdgeInfo(src_type, edge_type, dst_type);
+  if (edge_info == nullptr) {
+    ThrowKeyError("The edge type " + src_type + "_" + edge_type + "_" +
+                  dst_type + " doesn't exist in the graph info");
+  }
+  return ValueOrThrow(graphar::util::GetVertexChunkNum(
+      graph_info->GetPrefix(), edge_info, adj_list_type));
+}
+
 std::shared_ptr<graphar::Expression>
 expression_property(const std::string &name) {
   return graphar::_Property(name);
 }
 
+std::shared_ptr<graphar::Expression>
+expression_property_by_property(const graphar::Property &property) {
+  return graphar::_Property(property);
+}
+
 std::shared_ptr<graphar::Expression> expression_literal_bool(bool value) {
   return graphar::_Literal(value);
 }
@@ -595,6 +1171,10 @@ std::shared_ptr<graphar::Expression> expression_literal_i64(int64_t value) {
   return graphar::_Literal(value);
 }
 
+std::shared_ptr<graphar::Expression> expression_literal_f32(float value) {
+  return graphar::_Literal(value);
+}
+
 std::shared_ptr<graphar::Expression> expression_literal_f64(double value) {
   return graphar::_Literal(value);
 }
@@ -604,6 +1184,17 @@ expression_literal_string(const std::string &value) {
   return graphar::_Literal(value);
 }
 
+std::shared_ptr<graphar::Expression> expression_literal_date(int32_t days) {
+  return std::make_shared<ScalarLiteral>(
+      std::make_shared<arrow::Date32Scalar>(days));
+}
+
+std::shared_ptr<graphar::Expression>
+expression_literal_timestamp(int64_t millis) {
+  return std::make_shared<ScalarLiteral>(std::make_shared<arrow::TimestampScalar>(
+      millis, arrow::timestamp(arrow::TimeUnit::MILLI)));
+}
+
 std::shared_ptr<graphar::Expression>
 expression_equal(const std::shared_ptr<graphar::Expression> &lhs,
                  const std::shared_ptr<graphar::Expression> &rhs) {
@@ -657,4 +1248,108 @@ expression_not(const std::shared_ptr<graphar::Expression> &expr) {
   return graphar::_Not(expr);
 }
 
+// Arrow chunk readers
+std::shared_ptr<graphar::VertexPropertyArrowChunkReader>
+new_vertex_chunk_reader(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                        const std::string &type,
+                        const std::shared_ptr<graphar::PropertyGroup> &group) {
+  return ValueOrThrow(
+      graphar::VertexPropertyArrowChunkReader::Make(graph_info, type, group));
+}
+
+std::shared_ptr<graphar::VertexPropertyArrowChunkReader>
+new_vertex_chunk_reader_by_property(
+    const std::shared_ptr<graphar::GraphInfo> &graph_info,
+    const std::string &type, const std::string &property_name) {
+  return ValueOrThrow(graphar::VertexPropertyArrowChunkReader::Make(
+      graph_info, type, property_name));
+}
+
+void vertex_chunk_reader_seek(graphar::VertexPropertyArrowChunkReader &reader,
+                              graphar::IdType id) {
+  ThrowIfStatusError(reader.seek(id));
+}
+
+bool vertex_chunk_reader_next_chunk(
+    graphar::VertexPropertyArrowChunkReader &reader) {
+  return NextChunkOrThrow(reader.next_chunk());
+}
+
+graphar::IdType
+vertex_chunk_reader_chunk_num(graphar::VertexPropertyArrowChunkReader &reader) {
+  return reader.GetChunkNum();
+}
+
+void vertex_chunk_reader_export(graphar::VertexPropertyArrowChunkReader &reader,
+                                size_t out_array, size_t out_schema) {
+  ExportTable(ValueOrThrow(reader.GetChunk()), out_array, out_schema);
+}
+
+std::shared_ptr<graphar::AdjListArrowChunkReader>
+new_adj_list_chunk_reader(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                          const std::string &src_type,
+                          const std::string &edge_type,
+                          const std::string &dst_type,
+                          graphar::AdjListType adj_list_type) {
+  return ValueOrThrow(graphar::AdjListArrowChunkReader::Make(
+      graph_info, src_type, edge_type, dst_type, adj_list_type));
+}
+
+void adj_list_chunk_reader_seek(graphar::AdjListArrowChunkReader &reader,
+                                graphar::IdType offset) {
+  ThrowIfStatusError(reader.seek(offset));
+}
+
+void adj_list_chunk_reader_seek_src(graphar::AdjListArrowChunkReader &reader,
+                                    graphar::IdType id) {
+  ThrowIfStatusError(reader.seek_src(id));
+}
+
+void adj_list_chunk_reader_seek_dst(graphar::AdjListArrowChunkReader &reader,
+                                    graphar::IdType id) {
+  ThrowIfStatusError(reader.seek_dst(id));
+}
+
+bool adj_list_chunk_reader_next_chunk(graphar::AdjListArrowChunkReader &reader) {
+  return NextChunkOrThrow(reader.next_chunk());
+}
+
+void adj_list_chunk_reader_export(graphar::AdjListArrowChunkReader &reader,
+                                  size_t out_array, size_t out_schema) {
+  ExportTable(ValueOrThrow(reader.GetChunk()), out_array, out_schema);
+}
+
+// Adjacency offsets
+std::shared_ptr<graphar::AdjListOffsetArrowChunkReader>
+new_adj_list_offset_reader(const std::shared_ptr<graphar::GraphInfo> &graph_info,
+                           const std::string &src_type,
+                           const std::string &edge_type,
+                           const std::string &dst_type,
+                           graphar::AdjListType adj_list_type) {
+  return ValueOrThrow(graphar::AdjListOffsetArrowChunkReader::Make(
+      graph_info, src_type, edge_type, dst_type, adj_list_type));
+}
+
+bool adj_list_offset_reader_next_chunk(
+    graphar::AdjListOffsetArrowChunkReader &reader) {
+  return NextChunkOrThrow(reader.next_chunk());
+}
+
+rust::Vec<graphar::IdType>
+adj_list_offset_reader_chunk(graphar::AdjListOffsetArrowChunkReader &reader) {
+  auto array = ValueOrThrow(reader.GetChunk());
+  if (array->type_id() != arrow::Type::INT64) {
+    throw std::runtime_error(
+        "[TypeError] offset chunk is not of type int64, got " +
+        array->type()->ToString());
+  }
+  auto offsets = std::static_pointer_cast<arrow::Int64Array>(array);
+  rust::Vec<graphar::IdType> out;
+  out.reserve(offsets->length());
+  for (int64_t i = 0; i < offsets->length(); ++i) {
+    out.push_back(offsets->Value(i));
+  }
+  return out;
+}
+
 } // namespace graphar_rs
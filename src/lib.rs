@@ -1,9 +1,14 @@
 use cxx::CxxString;
 
+pub mod error;
 mod ffi;
 pub mod graph_builder;
 pub mod graph_info;
 pub mod graph_reader;
+pub mod schema;
+pub mod types;
+
+pub use error::{Error, Result};
 
 fn cxx_string_to_string(value: &CxxString) -> String {
     value
@@ -0,0 +1,138 @@
+//! Rust structs as vertex and edge schemas.
+//!
+//! With the `derive` feature, `#[derive(GraphArVertex)]` and
+//! `#[derive(GraphArEdge)]` implement [`GraphArVertex`] and [`GraphArEdge`]
+//! from a struct definition, so the schema, the builder calls and the reader
+//! calls are all written once:
+//!
+//! ```ignore
+//! use graphar::schema::{GraphArEdge, GraphArVertex};
+//!
+//! #[derive(GraphArVertex)]
+//! #[graphar(type = "person", chunk_size = 100)]
+//! struct Person {
+//!     #[graphar(primary)]
+//!     id: i64,
+//!     #[graphar(rename = "firstName", group = "name", file_type = "csv")]
+//!     first_name: String,
+//!     nickname: Option<String>,
+//! }
+//!
+//! #[derive(GraphArEdge)]
+//! #[graphar(
+//!     src_type = "person", edge_type = "knows", dst_type = "person",
+//!     chunk_size = 1024, src_chunk_size = 100, dst_chunk_size = 100,
+//!     adj_lists("ordered_by_source", "ordered_by_dest"),
+//! )]
+//! struct Knows {
+//!     #[graphar(src)]
+//!     src: i64,
+//!     #[graphar(dst)]
+//!     dst: i64,
+//!     weight: f64,
+//! }
+//! ```
+//!
+//! Struct attributes:
+//! - vertices: `type` (defaults to the struct name in snake case),
+//!   `chunk_size` (required) and `prefix` (defaults to `vertex/<type>/`);
+//! - edges: `src_type`, `edge_type`, `dst_type`, `chunk_size`,
+//!   `src_chunk_size` and `dst_chunk_size` (all required), `directed`
+//!   (defaults to `true`), `adj_lists(..)` (defaults to `ordered_by_source`)
+//!   and `prefix` (defaults to `edge/<src>_<edge>_<dst>/`);
+//! - both: `file_type` of the property groups, `parquet` by default.
+//!
+//! Field attributes: `primary`, `nullable` (implied by `Option<T>`),
+//! `rename`, `group` (fields without one share a default group) and
+//! `file_type` for the field's group; edges mark their id fields with `src`
+//! and `dst`.
+
+use crate::{
+    Result,
+    graph_builder::{EdgeBuilder, VertexBuilder},
+    graph_info::{DataType, EdgeInfo, VertexInfo},
+    graph_reader::{Edge, Vertex},
+    types::{Date, Timestamp},
+};
+
+#[cfg(feature = "derive")]
+pub use graphar_derive::{GraphArEdge, GraphArVertex};
+
+/// A Rust type stored as a GraphAr property.
+pub trait PropertyType {
+    /// Whether the property holds nulls, as `Option<T>` does.
+    const NULLABLE: bool = false;
+
+    fn data_type() -> DataType;
+}
+
+macro_rules! impl_property_type {
+    ($($ty:ty => $data_type:ident),* $(,)?) => {
+        $(
+            impl PropertyType for $ty {
+                fn data_type() -> DataType {
+                    DataType::$data_type()
+                }
+            }
+        )*
+    };
+}
+
+impl_property_type!(
+    bool => bool,
+    i32 => int32,
+    i64 => int64,
+    f32 => float32,
+    f64 => float64,
+    String => string,
+    Date => date,
+    Timestamp => timestamp,
+);
+
+macro_rules! impl_list_property_type {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl PropertyType for Vec<$ty> {
+                fn data_type() -> DataType {
+                    DataType::list(&<$ty as PropertyType>::data_type())
+                }
+            }
+        )*
+    };
+}
+
+// The element types GraphAr can read lists of.
+impl_list_property_type!(i32, i64, f32, f64, String);
+
+impl<T: PropertyType> PropertyType for Option<T> {
+    const NULLABLE: bool = true;
+
+    fn data_type() -> DataType {
+        T::data_type()
+    }
+}
+
+/// A struct stored as the properties of a vertex type.
+pub trait GraphArVertex: Sized {
+    const TYPE: &'static str;
+
+    fn vertex_info() -> Result<VertexInfo>;
+
+    fn to_builder(&self) -> VertexBuilder;
+
+    fn from_vertex(vertex: &Vertex) -> Result<Self>;
+}
+
+/// A struct stored as an edge type: its source and destination ids plus the
+/// edge properties.
+pub trait GraphArEdge: Sized {
+    const SRC_TYPE: &'static str;
+    const EDGE_TYPE: &'static str;
+    const DST_TYPE: &'static str;
+
+    fn edge_info() -> Result<EdgeInfo>;
+
+    fn to_builder(&self) -> EdgeBuilder;
+
+    fn from_edge(edge: &Edge) -> Result<Self>;
+}
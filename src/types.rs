@@ -0,0 +1,175 @@
+//! Value types for GraphAr properties.
+//!
+//! GraphAr stores `date` as days since the UNIX epoch (arrow `date32`) and
+//! `timestamp` as milliseconds since the UNIX epoch (arrow `timestamp[ms]`).
+//! With the `chrono` feature enabled, both convert to and from `chrono` types.
+
+use std::fmt::Display;
+
+/// A `date` property value: days since 1970-01-01.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Date(i32);
+
+impl Date {
+    pub fn from_days(days: i32) -> Self {
+        Self(days)
+    }
+
+    pub fn days(&self) -> i32 {
+        self.0
+    }
+}
+
+/// A `timestamp` property value: milliseconds since 1970-01-01T00:00:00Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    pub fn from_millis(millis: i64) -> Self {
+        Self(millis)
+    }
+
+    pub fn millis(&self) -> i64 {
+        self.0
+    }
+}
+
+/// A property value of any GraphAr type, as returned by the schema-driven
+/// accessors such as [`Vertex::get`](crate::graph_reader::Vertex::get).
+///
+/// `Null` is only produced for properties declared nullable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    Null,
+    Bool(bool),
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    Date(Date),
+    Timestamp(Timestamp),
+    List(Vec<PropertyValue>),
+}
+
+impl PropertyValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, PropertyValue::Null)
+    }
+}
+
+impl Display for PropertyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyValue::Null => write!(f, "null"),
+            PropertyValue::Bool(v) => write!(f, "{v}"),
+            PropertyValue::Int32(v) => write!(f, "{v}"),
+            PropertyValue::Int64(v) => write!(f, "{v}"),
+            PropertyValue::Float(v) => write!(f, "{v}"),
+            PropertyValue::Double(v) => write!(f, "{v}"),
+            PropertyValue::String(v) => write!(f, "{v}"),
+            PropertyValue::Date(v) => write!(f, "{}", v.days()),
+            PropertyValue::Timestamp(v) => write!(f, "{}", v.millis()),
+            PropertyValue::List(values) => {
+                write!(f, "[")?;
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{v}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::{Date, Timestamp};
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+    const UNIX_EPOCH: NaiveDate = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+
+    impl From<NaiveDate> for Date {
+        fn from(date: NaiveDate) -> Self {
+            // chrono dates span about ±262,000 years, well within `i32` days.
+            let days = date.signed_duration_since(UNIX_EPOCH).num_days();
+            Date::from_days(i32::try_from(days).expect("chrono dates fit in `i32` days"))
+        }
+    }
+
+    impl Date {
+        /// Returns `None` if the date is outside of chrono's supported range.
+        pub fn to_naive_date(&self) -> Option<NaiveDate> {
+            UNIX_EPOCH.checked_add_signed(chrono::TimeDelta::days(self.days() as i64))
+        }
+    }
+
+    impl From<DateTime<Utc>> for Timestamp {
+        fn from(ts: DateTime<Utc>) -> Self {
+            Timestamp::from_millis(ts.timestamp_millis())
+        }
+    }
+
+    impl From<NaiveDateTime> for Timestamp {
+        fn from(ts: NaiveDateTime) -> Self {
+            Timestamp::from_millis(ts.and_utc().timestamp_millis())
+        }
+    }
+
+    impl Timestamp {
+        /// Returns `None` if the timestamp is outside of chrono's supported range.
+        pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+            DateTime::from_timestamp_millis(self.millis())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temporal_ordering() {
+        assert!(Date::from_days(-1) < Date::from_days(0));
+        assert!(Timestamp::from_millis(1) > Timestamp::from_millis(0));
+        assert_eq!(Date::from_days(42).days(), 42);
+        assert_eq!(Timestamp::from_millis(42).millis(), 42);
+    }
+
+    #[test]
+    fn test_property_value_display() {
+        let list = PropertyValue::List(vec![PropertyValue::Int32(1), PropertyValue::Int32(2)]);
+        assert_eq!(list.to_string(), "[1, 2]");
+        assert_eq!(PropertyValue::Null.to_string(), "null");
+        assert!(PropertyValue::Null.is_null());
+        assert_eq!(PropertyValue::String("a".into()).to_string(), "a");
+        assert_eq!(PropertyValue::Date(Date(3)).to_string(), "3");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversions() {
+        use chrono::{DateTime, NaiveDate, Utc};
+
+        let date = NaiveDate::from_ymd_opt(2010, 7, 30).unwrap();
+        let days = Date::from(date);
+        assert_eq!(days, Date::from_days(14820));
+        assert_eq!(days.to_naive_date(), Some(date));
+        assert_eq!(
+            Date::from(NaiveDate::from_ymd_opt(1969, 12, 31).unwrap()),
+            Date::from_days(-1)
+        );
+        for date in [NaiveDate::MIN, NaiveDate::MAX] {
+            assert_eq!(Date::from(date).to_naive_date(), Some(date));
+        }
+
+        let ts = DateTime::parse_from_rfc3339("2010-07-30T15:19:53.298+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        let millis = Timestamp::from(ts);
+        assert_eq!(millis, Timestamp::from_millis(1_280_503_193_298));
+        assert_eq!(millis.to_datetime(), Some(ts));
+    }
+}
@@ -1,8 +1,73 @@
+// Each test crate only uses some of these helpers.
+#![allow(dead_code)]
+
 use std::path::{Path, PathBuf};
 
+use graphar::graph_info::{
+    AdjListType, AdjacentList, AdjacentListVector, Cardinality, DataType, EdgeInfo, FileType,
+    InfoVersion, Property, PropertyGroup, PropertyGroupVector, PropertyVec, VertexInfo,
+};
+
 /// Returns the root directory containing the bundled GraphAr fixtures.
 pub fn test_data_root() -> PathBuf {
     std::env::var("GAR_TEST_DIR")
         .map(PathBuf::from)
         .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("incubator-graphar-testing"))
 }
+
+/// Returns a plain property: not primary, not nullable, single cardinality.
+pub fn property(name: &str, data_type: &DataType) -> Property {
+    Property::new(name, data_type, false, false, Cardinality::Single)
+}
+
+/// Returns a `person` vertex info with chunk size 2 and `props` in one group.
+pub fn vertex_info(props: Vec<Property>, file_type: FileType) -> VertexInfo {
+    VertexInfo::new(
+        "person".into(),
+        2,
+        property_groups(props, file_type),
+        vec![],
+        "vertex/person/",
+        InfoVersion::new(1).unwrap(),
+    )
+    .unwrap()
+}
+
+/// Returns a `person knows person` edge info with edge chunk size 4, vertex
+/// chunk size 2, one adjacency list and `props` in one group.
+pub fn edge_info(
+    props: Vec<Property>,
+    file_type: FileType,
+    adj_list_type: AdjListType,
+    adj_list_prefix: &str,
+) -> EdgeInfo {
+    let mut adjs = AdjacentListVector::new();
+    adjs.add_adjacent_list(AdjacentList::new(adj_list_type, file_type, adj_list_prefix));
+    EdgeInfo::new(
+        "person",
+        "knows",
+        "person",
+        4,
+        2,
+        2,
+        true,
+        adjs,
+        property_groups(props, file_type),
+        "edge/person_knows_person/",
+        InfoVersion::new(1).unwrap(),
+    )
+    .unwrap()
+}
+
+// GraphAr rejects empty property groups, so no properties means no group.
+fn property_groups(props: Vec<Property>, file_type: FileType) -> PropertyGroupVector {
+    let mut pgv = PropertyGroupVector::new();
+    if !props.is_empty() {
+        let mut vec = PropertyVec::new();
+        for prop in props {
+            vec.add_property(prop);
+        }
+        pgv.add_property_group(PropertyGroup::new(vec, file_type, "").unwrap());
+    }
+    pgv
+}
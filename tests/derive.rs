@@ -0,0 +1,139 @@
+#![cfg(feature = "derive")]
+
+use graphar::{
+    graph_builder::{EdgesBuilder, VerticesBuilder},
+    graph_info::{AdjListType, GraphInfo},
+    graph_reader::{Edges, Vertices},
+    schema::{GraphArEdge, GraphArVertex},
+};
+use tempfile::tempdir;
+
+#[derive(Debug, Clone, PartialEq, GraphArVertex)]
+#[graphar(chunk_size = 2)]
+struct Person {
+    #[graphar(primary)]
+    id: i64,
+    #[graphar(rename = "firstName", group = "name", file_type = "csv")]
+    first_name: String,
+    #[graphar(group = "name")]
+    nickname: Option<String>,
+    scores: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, GraphArEdge)]
+#[graphar(
+    src_type = "person",
+    edge_type = "knows",
+    dst_type = "person",
+    chunk_size = 4,
+    src_chunk_size = 2,
+    dst_chunk_size = 2,
+    adj_lists("ordered_by_source", "ordered_by_dest")
+)]
+struct Knows {
+    #[graphar(src)]
+    src: i64,
+    #[graphar(dst)]
+    dst: i64,
+    weight: f64,
+}
+
+fn people() -> Vec<Person> {
+    (0..3)
+        .map(|id| Person {
+            id,
+            first_name: format!("p{id}"),
+            nickname: (id % 2 == 0).then(|| format!("n{id}")),
+            scores: vec![id as f64; id as usize],
+        })
+        .collect()
+}
+
+#[test]
+fn derived_vertex_info() {
+    let info = Person::vertex_info().unwrap();
+    assert_eq!(Person::TYPE, "person");
+    assert_eq!(info.ty(), "person");
+    assert_eq!(info.chunk_size(), 2);
+    assert_eq!(info.prefix(), "vertex/person/");
+
+    let groups = info.property_groups();
+    let names: Vec<Vec<String>> = groups
+        .iter()
+        .map(|group| group.properties().iter().map(|p| p.name()).collect())
+        .collect();
+    assert_eq!(names, [vec!["id", "scores"], vec!["firstName", "nickname"]]);
+
+    let props = groups[1].properties();
+    assert!(groups[0].properties()[0].is_primary());
+    assert!(!props[0].is_nullable());
+    assert!(props[1].is_nullable());
+}
+
+#[test]
+fn derived_roundtrip() {
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let vertex_info = Person::vertex_info().unwrap();
+    let edge_info = Knows::edge_info().unwrap();
+
+    let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+    for person in people() {
+        vb.add_vertex(person.to_builder()).unwrap();
+    }
+    vb.dump().unwrap();
+
+    let knows = vec![
+        Knows {
+            src: 0,
+            dst: 1,
+            weight: 0.5,
+        },
+        Knows {
+            src: 0,
+            dst: 2,
+            weight: 1.0,
+        },
+        Knows {
+            src: 2,
+            dst: 1,
+            weight: 1.5,
+        },
+    ];
+    let mut eb = EdgesBuilder::new(&edge_info, &prefix, AdjListType::OrderedBySource, 3).unwrap();
+    for edge in &knows {
+        eb.add_edge(edge.to_builder()).unwrap();
+    }
+    eb.dump().unwrap();
+
+    let graph_info = GraphInfo::new(
+        "g",
+        &vec![vertex_info],
+        &vec![edge_info],
+        &vec![],
+        &prefix,
+        None,
+    )
+    .unwrap();
+    let vertices = Vertices::new(&graph_info, Person::TYPE).unwrap();
+    let read: Vec<Person> = vertices
+        .begin()
+        .map(|vertex| Person::from_vertex(&vertex).unwrap())
+        .collect();
+    assert_eq!(read, people());
+
+    let edges = Edges::new(
+        &graph_info,
+        Knows::SRC_TYPE,
+        Knows::EDGE_TYPE,
+        Knows::DST_TYPE,
+        AdjListType::OrderedBySource,
+        None,
+    )
+    .unwrap();
+    let read: Vec<Knows> = edges
+        .begin()
+        .map(|edge| Knows::from_edge(&edge).unwrap())
+        .collect();
+    assert_eq!(read, knows);
+}
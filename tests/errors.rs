@@ -0,0 +1,70 @@
+mod common;
+
+use std::path::Path;
+
+use graphar::{
+    Error,
+    graph_builder::{VertexBuilder, VerticesBuilder},
+    graph_info::{Cardinality, DataType, FileType, GraphInfo, Property},
+    graph_reader::{Graph, Vertices},
+};
+use tempfile::tempdir;
+
+fn modern_graph_path() -> std::path::PathBuf {
+    common::test_data_root()
+        .join("modern_graph")
+        .join("modern_graph.graph.yml")
+}
+
+#[test]
+fn load_error_carries_path() {
+    let tmp = tempdir().unwrap();
+    let path = tmp.path().join("missing.graph.yml");
+    let err = GraphInfo::load(&path).err().unwrap();
+    assert_eq!(err.path(), Some(path.as_path()));
+    assert!(err.to_string().contains("missing.graph.yml"));
+}
+
+#[test]
+fn missing_keys_are_key_errors() {
+    let mut graph = Graph::open(modern_graph_path()).unwrap();
+    assert!(matches!(graph.vertices("robot"), Err(Error::Key(_))));
+    assert!(matches!(
+        graph.edges("person", "likes", "person"),
+        Err(Error::Key(_))
+    ));
+
+    let gi = GraphInfo::load(modern_graph_path()).unwrap();
+    let vertex = Vertices::new(&gi, "person").unwrap().begin().vertex();
+    for err in [
+        vertex.property::<i64>("missing").unwrap_err(),
+        vertex.get("missing").unwrap_err(),
+    ] {
+        assert!(matches!(err, Error::Key(_)), "{err}");
+        assert_eq!(err.property(), Some("missing"));
+        assert!(!err.is_transient());
+    }
+}
+
+#[test]
+fn builder_errors_carry_property() {
+    let info = common::vertex_info(
+        vec![Property::new(
+            "id",
+            &DataType::int64(),
+            true,
+            false,
+            Cardinality::Single,
+        )],
+        FileType::Parquet,
+    );
+
+    let tmp = tempdir().unwrap();
+    let mut builder = VerticesBuilder::new(&info, tmp.path(), 0).unwrap();
+    let mut vertex = VertexBuilder::new();
+    vertex.add_null("id".into());
+    let err = builder.add_vertex(vertex).unwrap_err();
+    assert!(matches!(err, Error::Invalid(_)));
+    assert_eq!(err.property(), Some("id"));
+    assert_eq!(err.path(), None::<&Path>);
+}
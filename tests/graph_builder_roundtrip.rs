@@ -0,0 +1,428 @@
+mod common;
+
+use common::{edge_info, property, vertex_info};
+use graphar::{
+    graph_builder::{EdgeBuilder, EdgesBuilder, VertexBuilder, VerticesBuilder},
+    graph_info::{AdjListType, Cardinality, DataType, FileType, GraphInfo, Property, VertexInfo},
+    graph_reader::{EdgeTriple, Edges, NeighborReader, Vertices},
+    types::{Date, PropertyValue, Timestamp},
+};
+use tempfile::tempdir;
+
+fn id_property() -> Property {
+    Property::new("id", &DataType::int64(), true, false, Cardinality::Single)
+}
+
+fn person_info(file_type: FileType) -> VertexInfo {
+    vertex_info(
+        vec![
+            id_property(),
+            property("scores", &DataType::list(&DataType::float64())),
+            property("tags", &DataType::list(&DataType::string())),
+        ],
+        file_type,
+    )
+}
+
+#[test]
+fn list_properties_roundtrip() {
+    // Arrow's CSV reader and writer have no list support, so only columnar formats
+    for file_type in [FileType::Parquet, FileType::Orc] {
+        let tmp = tempdir().unwrap();
+        let prefix = format!("{}/", tmp.path().display());
+        let vertex_info = person_info(file_type);
+
+        let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+        for id in 0..3_i64 {
+            let mut v = VertexBuilder::new();
+            v.add_property("id".into(), id);
+            v.add_property("scores".into(), vec![id as f64, id as f64 + 0.5]);
+            v.add_property("tags".into(), vec![format!("tag{id}"); id as usize]);
+            vb.add_vertex(v).unwrap();
+        }
+        vb.dump().unwrap();
+
+        let graph_info =
+            GraphInfo::new("g", &vec![vertex_info], &vec![], &vec![], &prefix, None).unwrap();
+        let vertices = Vertices::new(&graph_info, "person").unwrap();
+        assert_eq!(vertices.len(), 3);
+
+        for (id, vertex) in vertices.begin().enumerate() {
+            let id = id as i64;
+            assert_eq!(vertex.property::<i64>("id").unwrap(), id);
+            assert_eq!(
+                vertex.property::<Vec<f64>>("scores").unwrap(),
+                vec![id as f64, id as f64 + 0.5]
+            );
+            assert_eq!(
+                vertex.property::<Vec<String>>("tags").unwrap(),
+                vec![format!("tag{id}"); id as usize]
+            );
+        }
+    }
+}
+
+#[test]
+fn temporal_properties_roundtrip() {
+    let vertex_info = vertex_info(
+        vec![
+            id_property(),
+            property("birthday", &DataType::date()),
+            property("creationDate", &DataType::timestamp()),
+        ],
+        FileType::Parquet,
+    );
+
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+    for id in 0..3_i64 {
+        let mut v = VertexBuilder::new();
+        v.add_property("id".into(), id);
+        v.add_property("birthday".into(), Date::from_days(14820 + id as i32));
+        v.add_property(
+            "creationDate".into(),
+            Timestamp::from_millis(1_280_503_193_298 + id),
+        );
+        vb.add_vertex(v).unwrap();
+    }
+    vb.dump().unwrap();
+
+    let graph_info =
+        GraphInfo::new("g", &vec![vertex_info], &vec![], &vec![], &prefix, None).unwrap();
+    let vertices = Vertices::new(&graph_info, "person").unwrap();
+    for (id, vertex) in vertices.begin().enumerate() {
+        assert_eq!(
+            vertex.property::<Date>("birthday").unwrap(),
+            Date::from_days(14820 + id as i32)
+        );
+        assert_eq!(
+            vertex.property::<Timestamp>("creationDate").unwrap(),
+            Timestamp::from_millis(1_280_503_193_298 + id as i64)
+        );
+    }
+}
+
+#[test]
+fn edge_list_properties_roundtrip() {
+    for file_type in [FileType::Parquet, FileType::Orc] {
+        let edge_info = edge_info(
+            vec![
+                property("weights", &DataType::list(&DataType::float64())),
+                property("tags", &DataType::list(&DataType::string())),
+            ],
+            file_type,
+            AdjListType::OrderedBySource,
+            "ordered_by_source/",
+        );
+
+        let tmp = tempdir().unwrap();
+        let prefix = format!("{}/", tmp.path().display());
+        let mut eb =
+            EdgesBuilder::new(&edge_info, &prefix, AdjListType::OrderedBySource, 3).unwrap();
+        for (src, dst) in [(0, 1), (1, 2), (2, 0)] {
+            let mut e = EdgeBuilder::new(src, dst);
+            e.add_property("weights".into(), vec![src as f64; src as usize + 1]);
+            e.add_property("tags".into(), vec![format!("tag{dst}")]);
+            eb.add_edge(e).unwrap();
+        }
+        eb.dump().unwrap();
+
+        let graph_info =
+            GraphInfo::new("g", &vec![], &vec![edge_info], &vec![], &prefix, None).unwrap();
+        let edges = Edges::new(
+            &graph_info,
+            "person",
+            "knows",
+            "person",
+            AdjListType::OrderedBySource,
+            None,
+        )
+        .unwrap();
+        let mut iter = edges.begin();
+        let mut count = 0;
+        while !iter.is_end() {
+            let (src, dst) = (iter.source(), iter.destination());
+            assert_eq!(
+                iter.property::<Vec<f64>>("weights").unwrap(),
+                vec![src as f64; src as usize + 1]
+            );
+            let edge = iter.edge();
+            assert_eq!(
+                edge.property::<Vec<String>>("tags").unwrap(),
+                vec![format!("tag{dst}")]
+            );
+            assert_eq!(
+                edge.get("tags").unwrap(),
+                PropertyValue::List(vec![PropertyValue::String(format!("tag{dst}"))])
+            );
+            iter.advance();
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+}
+
+#[test]
+fn edge_temporal_properties_roundtrip() {
+    let edge_info = edge_info(
+        vec![
+            property("creationDate", &DataType::timestamp()),
+            property("since", &DataType::date()),
+        ],
+        FileType::Parquet,
+        AdjListType::OrderedBySource,
+        "ordered_by_source/",
+    );
+
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let mut eb = EdgesBuilder::new(&edge_info, &prefix, AdjListType::OrderedBySource, 3).unwrap();
+    for (i, (src, dst)) in [(0, 1), (1, 2), (2, 0)].into_iter().enumerate() {
+        let mut e = EdgeBuilder::new(src, dst);
+        // 2010-07-30T15:19:53.298+0000 and 2010-07-30
+        e.add_property(
+            "creationDate".into(),
+            Timestamp::from_millis(1_280_503_193_298 + i as i64),
+        );
+        e.add_property("since".into(), Date::from_days(14820 + i as i32));
+        eb.add_edge(e).unwrap();
+    }
+    eb.dump().unwrap();
+
+    let graph_info =
+        GraphInfo::new("g", &vec![], &vec![edge_info], &vec![], &prefix, None).unwrap();
+    let edges = Edges::new(
+        &graph_info,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+        None,
+    )
+    .unwrap();
+    let mut begin = edges.begin();
+    assert_eq!(
+        begin.property::<Timestamp>("creationDate").unwrap(),
+        Timestamp::from_millis(1_280_503_193_298)
+    );
+    assert_eq!(
+        begin.property::<Date>("since").unwrap(),
+        Date::from_days(14820)
+    );
+    for edge in edges.begin() {
+        let i = edge.src();
+        assert_eq!(
+            edge.property::<Timestamp>("creationDate").unwrap(),
+            Timestamp::from_millis(1_280_503_193_298 + i)
+        );
+        assert_eq!(
+            edge.get("since").unwrap(),
+            PropertyValue::Date(Date::from_days(14820 + i as i32))
+        );
+    }
+}
+
+#[test]
+fn multi_valued_properties_roundtrip() {
+    let vertex_info = vertex_info(
+        vec![
+            id_property(),
+            Property::new(
+                "interests",
+                &DataType::string(),
+                false,
+                false,
+                Cardinality::Set,
+            ),
+            Property::new(
+                "visits",
+                &DataType::int64(),
+                false,
+                false,
+                Cardinality::List,
+            ),
+        ],
+        FileType::Parquet,
+    );
+
+    let yaml = vertex_info.dump().unwrap();
+    assert!(yaml.contains("cardinality: set"));
+    assert!(yaml.contains("cardinality: list"));
+
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+    for id in 0..3_i64 {
+        let mut v = VertexBuilder::new();
+        v.add_property("id".into(), id);
+        v.add_multi_property(
+            "interests".into(),
+            Cardinality::Set,
+            vec![
+                "music".to_string(),
+                format!("topic{id}"),
+                "music".to_string(),
+            ],
+        )
+        .unwrap();
+        v.add_multi_property("visits".into(), Cardinality::List, vec![id, id, id + 1])
+            .unwrap();
+        vb.add_vertex(v).unwrap();
+    }
+    vb.dump().unwrap();
+
+    let graph_info =
+        GraphInfo::new("g", &vec![vertex_info], &vec![], &vec![], &prefix, None).unwrap();
+    let vertices = Vertices::new(&graph_info, "person").unwrap();
+    for (id, vertex) in vertices.begin().enumerate() {
+        let id = id as i64;
+        assert_eq!(
+            vertex.property::<Vec<String>>("interests").unwrap(),
+            vec!["music".to_string(), format!("topic{id}")]
+        );
+        assert_eq!(
+            vertex.property::<Vec<i64>>("visits").unwrap(),
+            vec![id, id, id + 1]
+        );
+        assert_eq!(
+            vertex.get("visits").unwrap(),
+            PropertyValue::List(vec![
+                PropertyValue::Int64(id),
+                PropertyValue::Int64(id),
+                PropertyValue::Int64(id + 1),
+            ])
+        );
+    }
+}
+
+#[test]
+fn set_written_as_list_is_deduplicated() {
+    let vertex_info = vertex_info(
+        vec![
+            id_property(),
+            Property::new(
+                "ratings",
+                &DataType::float64(),
+                false,
+                false,
+                Cardinality::Set,
+            ),
+        ],
+        FileType::Parquet,
+    );
+
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+    let mut v = VertexBuilder::new();
+    v.add_property("id".into(), 0_i64);
+    v.add_multi_property(
+        "ratings".into(),
+        Cardinality::List,
+        vec![2.5, 1.0, 2.5, 1.0, 3.0],
+    )
+    .unwrap();
+    vb.add_vertex(v).unwrap();
+    vb.dump().unwrap();
+
+    let graph_info =
+        GraphInfo::new("g", &vec![vertex_info], &vec![], &vec![], &prefix, None).unwrap();
+    let vertices = Vertices::new(&graph_info, "person").unwrap();
+    let vertex = vertices.begin().vertex();
+    assert_eq!(
+        vertex.property::<Vec<f64>>("ratings").unwrap(),
+        vec![2.5, 1.0, 3.0]
+    );
+}
+
+#[test]
+fn null_properties_roundtrip() {
+    let vertex_info = vertex_info(
+        vec![
+            id_property(),
+            Property::new(
+                "nickname",
+                &DataType::string(),
+                false,
+                true,
+                Cardinality::Single,
+            ),
+        ],
+        FileType::Parquet,
+    );
+
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+    for id in 0..3_i64 {
+        let mut v = VertexBuilder::new();
+        v.add_property("id".into(), id);
+        let nickname = (id % 2 == 0).then(|| format!("nick{id}"));
+        v.add_property("nickname".into(), nickname);
+        vb.add_vertex(v).unwrap();
+    }
+    vb.dump().unwrap();
+
+    let graph_info =
+        GraphInfo::new("g", &vec![vertex_info], &vec![], &vec![], &prefix, None).unwrap();
+    let vertices = Vertices::new(&graph_info, "person").unwrap();
+    for (id, vertex) in vertices.begin().enumerate() {
+        let expected = (id % 2 == 0).then(|| format!("nick{id}"));
+        assert_eq!(
+            vertex.property::<Option<String>>("nickname").unwrap(),
+            expected
+        );
+        let dynamic = vertex.get("nickname").unwrap();
+        assert_eq!(dynamic.is_null(), id % 2 != 0);
+    }
+}
+
+#[test]
+fn unordered_neighbors_roundtrip() {
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let vertex_info = person_info(FileType::Parquet);
+    let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+    for id in 0..3_i64 {
+        let mut v = VertexBuilder::new();
+        v.add_property("id".into(), id);
+        v.add_property("scores".into(), Vec::<f64>::new());
+        v.add_property("tags".into(), Vec::<String>::new());
+        vb.add_vertex(v).unwrap();
+    }
+    vb.dump().unwrap();
+
+    let edge_info = edge_info(
+        vec![],
+        FileType::Parquet,
+        AdjListType::UnorderedBySource,
+        "unordered_by_source/",
+    );
+    let mut eb = EdgesBuilder::new(&edge_info, &prefix, AdjListType::UnorderedBySource, 3).unwrap();
+    for (src, dst) in [(2, 0), (0, 1), (1, 2), (0, 2)] {
+        eb.add_edge(EdgeBuilder::new(src, dst)).unwrap();
+    }
+    eb.dump().unwrap();
+
+    let graph_info = GraphInfo::new(
+        "g",
+        &vec![vertex_info],
+        &vec![edge_info],
+        &vec![],
+        &prefix,
+        None,
+    )
+    .unwrap();
+    let triple = EdgeTriple::new("person", "knows", "person");
+    let mut reader = NeighborReader::new(&graph_info);
+
+    let mut out = reader.out_neighbors(&triple, 0).unwrap();
+    out.sort_unstable();
+    assert_eq!(out, vec![1, 2]);
+    assert_eq!(reader.out_degree(&triple, 2).unwrap(), 1);
+    let mut inn = reader.in_neighbors(&triple, 2).unwrap();
+    inn.sort_unstable();
+    assert_eq!(inn, vec![0, 1]);
+    assert_eq!(reader.in_degree(&triple, 0).unwrap(), 1);
+}
@@ -23,13 +23,16 @@ fn modern_graph_indices() {
     let graph_info = GraphInfo::load(path).unwrap();
 
     // Check expected indices from file order
-    assert_eq!(graph_info.vertex_info_index("person"), 0);
-    assert_eq!(graph_info.vertex_info_index("software"), 1);
+    assert_eq!(graph_info.vertex_info_index("person"), Some(0));
+    assert_eq!(graph_info.vertex_info_index("software"), Some(1));
 
     // Edge indices (order as listed in the YAML)
-    assert_eq!(graph_info.edge_info_index("person", "knows", "person"), 0);
+    assert_eq!(
+        graph_info.edge_info_index("person", "knows", "person"),
+        Some(0)
+    );
     assert_eq!(
         graph_info.edge_info_index("person", "created", "software"),
-        1
+        Some(1)
     );
 }
@@ -0,0 +1,148 @@
+use std::{fs, path::Path};
+
+use graphar::{
+    graph_builder::{EdgeBuilder, EdgesBuilder, VertexBuilder, VerticesBuilder},
+    graph_info::{
+        AdjListType, AdjacentList, AdjacentListVector, Cardinality, DataType, EdgeInfo, FileKind,
+        FileType, GraphInfo, InfoVersion, Issue, Property, PropertyGroup, PropertyGroupVector,
+        PropertyVec, VertexInfo,
+    },
+};
+use tempfile::tempdir;
+
+fn property_group(name: &str, data_type: DataType, is_primary: bool) -> PropertyGroupVector {
+    let mut props = PropertyVec::new();
+    props.add_property(Property::new(
+        name,
+        &data_type,
+        is_primary,
+        false,
+        Cardinality::Single,
+    ));
+    let mut pgv = PropertyGroupVector::new();
+    pgv.add_property_group(
+        PropertyGroup::new(props, FileType::Parquet, format!("{name}/")).unwrap(),
+    );
+    pgv
+}
+
+// Three people in two vertex chunks, with `knows` edges from both chunks.
+fn write_graph(prefix: &str) -> GraphInfo {
+    let ver = InfoVersion::new(1).unwrap();
+    let vertex_info = VertexInfo::new(
+        "person".into(),
+        2,
+        property_group("id", DataType::int64(), true),
+        vec![],
+        "vertex/person/",
+        ver.clone(),
+    )
+    .unwrap();
+    let mut adjs = AdjacentListVector::new();
+    adjs.add_adjacent_list(AdjacentList::new(
+        AdjListType::OrderedBySource,
+        FileType::Parquet,
+        "ordered_by_source/",
+    ));
+    let edge_info = EdgeInfo::new(
+        "person",
+        "knows",
+        "person",
+        2,
+        2,
+        2,
+        true,
+        adjs,
+        property_group("weight", DataType::float64(), false),
+        "edge/person_knows_person/",
+        ver,
+    )
+    .unwrap();
+
+    let mut vb = VerticesBuilder::new(&vertex_info, prefix, 0).unwrap();
+    for id in 0..3_i64 {
+        let mut v = VertexBuilder::new();
+        v.add_property("id".into(), id);
+        vb.add_vertex(v).unwrap();
+    }
+    vb.dump().unwrap();
+
+    let mut eb = EdgesBuilder::new(&edge_info, prefix, AdjListType::OrderedBySource, 3).unwrap();
+    for (src, dst) in [(0, 1), (0, 2), (1, 2), (2, 0)] {
+        let mut e = EdgeBuilder::new(src, dst);
+        e.add_property("weight".into(), 0.5);
+        eb.add_edge(e).unwrap();
+    }
+    eb.dump().unwrap();
+
+    GraphInfo::new(
+        "g",
+        &vec![vertex_info],
+        &vec![edge_info],
+        &vec![],
+        prefix,
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn written_graph_is_valid() {
+    let tmp = tempdir().unwrap();
+    let graph_info = write_graph(&format!("{}/", tmp.path().display()));
+    let report = graph_info.validate();
+    assert!(report.is_valid(), "{report}");
+}
+
+#[test]
+fn reports_missing_and_extra_files() {
+    let tmp = tempdir().unwrap();
+    let graph_info = write_graph(&format!("{}/", tmp.path().display()));
+
+    let chunk_dir = tmp.path().join("vertex/person/id");
+    fs::remove_file(chunk_dir.join("chunk1")).unwrap();
+    fs::write(chunk_dir.join("chunk7"), b"").unwrap();
+    fs::remove_file(
+        tmp.path()
+            .join("edge/person_knows_person/ordered_by_source/offset/chunk0"),
+    )
+    .unwrap();
+
+    let report = graph_info.validate();
+    assert!(!report.is_valid());
+    assert!(report.issues().contains(&Issue::MissingFile {
+        kind: FileKind::VertexChunk,
+        path: chunk_dir.join("chunk1"),
+    }));
+    let missing: Vec<&Path> = report.missing_files().collect();
+    assert_eq!(missing.len(), 2, "{report}");
+    assert!(missing.iter().any(|path| path.ends_with("offset/chunk0")));
+    assert_eq!(
+        report.extra_files().collect::<Vec<_>>(),
+        [chunk_dir.join("chunk7")]
+    );
+}
+
+#[test]
+fn missing_count_file_skips_chunks() {
+    let tmp = tempdir().unwrap();
+    let graph_info = write_graph(&format!("{}/", tmp.path().display()));
+    let count = tmp.path().join("vertex/person/vertex_count");
+    fs::remove_file(&count).unwrap();
+
+    let report = graph_info.validate();
+    assert_eq!(
+        report.issues(),
+        [Issue::MissingFile {
+            kind: FileKind::VertexCount,
+            path: count,
+        }]
+    );
+}
+
+#[test]
+fn invalid_metadata() {
+    let graph_info = GraphInfo::new("g", &vec![], &vec![], &vec![], "", None).unwrap();
+    let report = graph_info.validate();
+    assert_eq!(report.issues(), [Issue::InvalidGraphInfo]);
+}
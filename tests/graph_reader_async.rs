@@ -0,0 +1,88 @@
+#![cfg(feature = "tokio")]
+
+mod common;
+
+use std::time::Duration;
+
+use graphar::graph_reader::{AsyncGraph, VertexFilter, col};
+use tokio_stream::StreamExt;
+
+async fn ldbc_sample() -> AsyncGraph {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("csv")
+        .join("ldbc_sample.graph.yml");
+    AsyncGraph::open(path).await.unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn async_streams_match_collections() {
+    let graph = ldbc_sample().await.with_buffer(4);
+
+    let ids: Vec<i64> = graph
+        .vertices("person")
+        .map(|v| v.unwrap().id())
+        .collect()
+        .await;
+    assert_eq!(ids, (0..ids.len() as i64).collect::<Vec<_>>());
+    assert!(!ids.is_empty());
+
+    let males: Vec<String> = graph
+        .filtered_vertices(
+            "person",
+            VertexFilter::property("gender", col("gender").eq("male")),
+        )
+        .map(|v| v.unwrap().property::<String>("gender").unwrap())
+        .collect()
+        .await;
+    assert!(!males.is_empty());
+    assert!(males.iter().all(|g| g == "male"));
+
+    let edges: Vec<(i64, i64)> = graph
+        .edges("person", "knows", "person")
+        .map(|e| e.map(|e| (e.src(), e.dst())).unwrap())
+        .collect()
+        .await;
+    assert!(!edges.is_empty());
+    assert!(edges.windows(2).all(|w| w[0].0 <= w[1].0));
+
+    let mut missing = graph.vertices("robot");
+    assert!(missing.next().await.unwrap().is_err());
+    assert!(missing.next().await.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dropping_a_stream_stops_the_reader() {
+    let graph = ldbc_sample().await.with_buffer(1);
+    let mut vertices = graph.vertices("person");
+    assert!(vertices.next().await.unwrap().is_ok());
+    drop(vertices);
+
+    // The graph stays usable after a cancelled stream.
+    let first = graph.vertices("person").next().await.unwrap().unwrap();
+    assert_eq!(first.id(), 0);
+}
+
+#[test]
+fn slow_streams_hold_no_blocking_threads() {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .max_blocking_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+    runtime.block_on(async {
+        let graph = ldbc_sample().await.with_buffer(1);
+        // More stalled streams than blocking threads, each with a full channel
+        let mut streams = Vec::new();
+        for _ in 0..8 {
+            let mut vertices = graph.vertices("person");
+            assert!(vertices.next().await.unwrap().is_ok());
+            streams.push(vertices);
+        }
+
+        let unrelated = tokio::task::spawn_blocking(|| 42);
+        let result = tokio::time::timeout(Duration::from_secs(10), unrelated).await;
+        assert_eq!(result.expect("blocking pool is exhausted").unwrap(), 42);
+        drop(streams);
+    });
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "arrow")]
+
+mod common;
+
+use std::fs;
+
+use graphar::{
+    graph_builder::{EdgeBuilder, EdgesBuilder},
+    graph_info::{
+        AdjListType, AdjacentList, AdjacentListVector, EdgeInfo, FileType, GraphInfo, InfoVersion,
+        PropertyGroupVector,
+    },
+    graph_reader::{
+        Edges,
+        chunk::{AdjListChunkReader, VertexChunkReader},
+    },
+};
+use tempfile::tempdir;
+
+fn modern_graph() -> GraphInfo {
+    let path = common::test_data_root()
+        .join("modern_graph")
+        .join("modern_graph.graph.yml");
+    GraphInfo::load(path).unwrap()
+}
+
+#[test]
+fn vertex_chunks_as_record_batches() {
+    let graph_info = modern_graph();
+    let reader = VertexChunkReader::with_property(&graph_info, "person", "name").unwrap();
+    let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+    let rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+    assert_eq!(rows, 4);
+    assert!(batches[0].schema().field_with_name("name").is_ok());
+
+    let group = graph_info
+        .vertex_info("person")
+        .and_then(|info| info.property_group("name"))
+        .unwrap();
+    let mut reader = VertexChunkReader::new(&graph_info, "person", &group).unwrap();
+    assert!(reader.chunk_num() >= 1);
+    reader.seek(0).unwrap();
+    assert!(reader.chunk().unwrap().num_rows() > 0);
+}
+
+#[test]
+fn adj_list_chunks_as_record_batches() {
+    let graph_info = modern_graph();
+    let edges = Edges::new(
+        &graph_info,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+        None,
+    )
+    .unwrap();
+
+    let reader = AdjListChunkReader::new(
+        &graph_info,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+    )
+    .unwrap();
+    let rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+    assert_eq!(rows, edges.len());
+}
+
+#[test]
+fn adj_list_chunks_yield_next_chunk_errors() {
+    let mut adjs = AdjacentListVector::new();
+    adjs.add_adjacent_list(AdjacentList::new(
+        AdjListType::OrderedBySource,
+        FileType::Parquet,
+        "ordered_by_source/",
+    ));
+    let edge_info = EdgeInfo::new(
+        "person",
+        "knows",
+        "person",
+        2,
+        2,
+        2,
+        true,
+        adjs,
+        PropertyGroupVector::new(),
+        "edge/person_knows_person/",
+        InfoVersion::new(1).unwrap(),
+    )
+    .unwrap();
+
+    // Two vertex chunks, each with edges
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let mut eb = EdgesBuilder::new(&edge_info, &prefix, AdjListType::OrderedBySource, 3).unwrap();
+    for (src, dst) in [(0, 1), (2, 0)] {
+        eb.add_edge(EdgeBuilder::new(src, dst)).unwrap();
+    }
+    eb.dump().unwrap();
+    fs::remove_file(
+        tmp.path()
+            .join("edge/person_knows_person/ordered_by_source/edge_count1"),
+    )
+    .unwrap();
+
+    let graph_info =
+        GraphInfo::new("g", &vec![], &vec![edge_info], &vec![], &prefix, None).unwrap();
+    let mut reader = AdjListChunkReader::new(
+        &graph_info,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+    )
+    .unwrap();
+    assert_eq!(reader.next().unwrap().unwrap().num_rows(), 1);
+    // Moving on to the second vertex chunk needs its edge count
+    assert!(reader.next().unwrap().is_err());
+    assert!(reader.next().is_none());
+}
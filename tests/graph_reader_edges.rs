@@ -1,7 +1,9 @@
 mod common;
 
+use graphar::Error;
 use graphar::graph_info::{AdjListType, GraphInfo};
-use graphar::graph_reader::Edges;
+use graphar::graph_reader::{Adjacency, EdgeCursor, EdgeTriple, Edges, NeighborReader};
+use graphar::types::Timestamp;
 
 #[test]
 fn edges_iterate_and_properties() {
@@ -11,7 +13,7 @@ fn edges_iterate_and_properties() {
         .join("ldbc_sample.graph.yml");
     let graph_info = GraphInfo::load(path).unwrap();
 
-    let mut edges = Edges::new(
+    let edges = Edges::new(
         &graph_info,
         "person",
         "knows",
@@ -22,20 +24,23 @@ fn edges_iterate_and_properties() {
     .unwrap();
 
     assert!(!edges.is_empty());
-    assert!(edges.len() > 0);
-    let mut begin = edges.begin();
+    let mut begin = edges.cursor();
     assert!(!begin.is_end());
 
-    // Read a property from the first edge
+    // Read a property from the first edge. ldbc_sample stores `creationDate`
+    // as a string, so the typed accessors reject it; see
+    // `edge_temporal_properties_roundtrip` for a timestamp column.
     let creation_date = begin.property::<String>("creationDate").unwrap();
     assert!(!creation_date.is_empty());
-    // FIXME
-    // assert_eq!(creation_date, "2010-07-30T15:19:53.298+0000");
+    assert!(matches!(
+        begin.property::<Timestamp>("creationDate"),
+        Err(Error::Type(_))
+    ));
 
     // Some navigation sanity checks
     let _ = begin.source();
     let _ = begin.destination();
-    begin.next();
+    begin.advance();
     let _ = begin.source();
     let _ = begin.destination();
 
@@ -50,8 +55,8 @@ fn edges_iterate_and_properties() {
     assert!(begin.cur_offset() >= 0);
 
     // find_src/dst should locate valid positions for known ids
-    let end = edges.end();
-    let begin_for_find = edges.begin();
+    let end = EdgeCursor::from(edges.end());
+    let begin_for_find = edges.cursor();
     let found_src0 = edges.find_src(0, &begin_for_find);
     let found_dst87 = edges.find_dst(87, &begin_for_find);
     assert!(found_src0 != end);
@@ -67,7 +72,7 @@ fn edges_iterate_and_properties() {
         Some(0..1),
     )
     .unwrap();
-    assert!(edges_small.len() > 0);
+    assert!(!edges_small.is_empty());
     assert!(edges_small.len() < edges.len());
 }
 
@@ -79,7 +84,7 @@ fn edges_ordered_by_dest() {
         .join("ldbc_sample.graph.yml");
     let gi = GraphInfo::load(path).unwrap();
 
-    let mut edges = Edges::new(
+    let edges = Edges::new(
         &gi,
         "person",
         "knows",
@@ -95,7 +100,158 @@ fn edges_ordered_by_dest() {
     // Property exists
     assert!(!it.property::<String>("creationDate").unwrap().is_empty());
     // Move a bit and ensure we still have valid positions
-    it.next();
+    it.advance();
     let _ = it.source();
     let _ = it.destination();
 }
+
+#[test]
+fn edges_iterator_adapters() {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("csv")
+        .join("ldbc_sample.graph.yml");
+    let gi = GraphInfo::load(path).unwrap();
+
+    let mut edges = Edges::new(
+        &gi,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+        None,
+    )
+    .unwrap();
+    let len = edges.len();
+
+    let iter = edges.begin();
+    assert_eq!(iter.size_hint(), (len, Some(len)));
+    assert_eq!(iter.len(), len);
+    assert_eq!(iter.count(), len);
+
+    // After a jump only the upper bound is known
+    let mut iter = edges.cursor();
+    assert!(iter.next_src());
+    let (lower, upper) = iter.size_hint();
+    assert_eq!(lower, 0);
+    assert_eq!(upper, Some(len));
+    assert!(iter.count() < len);
+
+    // Sources are non-decreasing for an ordered_by_source adjacency list
+    let sources: Vec<i64> = (&mut edges).into_iter().map(|e| e.src()).collect();
+    assert_eq!(sources.len(), len);
+    assert!(sources.windows(2).all(|w| w[0] <= w[1]));
+
+    let from_zero: Vec<i64> = (&mut edges)
+        .into_iter()
+        .filter(|e| e.src() == 0)
+        .map(|e| e.dst())
+        .collect();
+    assert!(!from_zero.is_empty());
+
+    let first_three: Vec<String> = edges
+        .into_iter()
+        .take(3)
+        .map(|e| e.property::<String>("creationDate").unwrap())
+        .collect();
+    assert_eq!(first_three.len(), 3);
+}
+
+#[test]
+fn adjacency_offsets_match_edges() {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("csv")
+        .join("ldbc_sample.graph.yml");
+    let gi = GraphInfo::load(path).unwrap();
+
+    for adj_list_type in [AdjListType::OrderedBySource, AdjListType::OrderedByDest] {
+        let adjacency = Adjacency::new(&gi, "person", "knows", "person", adj_list_type).unwrap();
+        let edges = Edges::new(&gi, "person", "knows", "person", adj_list_type, None).unwrap();
+
+        let mut degrees = vec![0; adjacency.vertex_num() as usize];
+        for edge in edges.clone() {
+            let id = match adj_list_type {
+                AdjListType::OrderedBySource => edge.src(),
+                _ => edge.dst(),
+            };
+            degrees[id as usize] += 1;
+        }
+        for (id, &degree) in degrees.iter().enumerate() {
+            assert_eq!(adjacency.degree(id as i64), Some(degree));
+        }
+        assert_eq!(degrees.iter().sum::<usize>(), edges.len());
+        assert_eq!(adjacency.range(adjacency.vertex_num()), None);
+        assert_eq!(adjacency.range(-1), None);
+    }
+
+    let edges = Edges::new(
+        &gi,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+        None,
+    )
+    .unwrap();
+    let adjacency = Adjacency::new(
+        &gi,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+    )
+    .unwrap();
+    let begin = edges.cursor();
+    let found = edges.find_src(0, &begin);
+    assert_eq!(adjacency.range(0).unwrap().start, found.cur_offset());
+
+    assert!(
+        Adjacency::new(
+            &gi,
+            "person",
+            "knows",
+            "person",
+            AdjListType::UnorderedBySource
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn neighbor_reader_matches_edges() {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("csv")
+        .join("ldbc_sample.graph.yml");
+    let gi = GraphInfo::load(path).unwrap();
+    let triple = EdgeTriple::new("person", "knows", "person");
+    let mut reader = NeighborReader::new(&gi);
+
+    let edges = Edges::new(
+        &gi,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+        None,
+    )
+    .unwrap();
+    let pairs: Vec<(i64, i64)> = edges.into_iter().map(|e| (e.src(), e.dst())).collect();
+
+    for id in [0, 87] {
+        let out: Vec<i64> = pairs.iter().filter(|p| p.0 == id).map(|p| p.1).collect();
+        let mut inn: Vec<i64> = pairs.iter().filter(|p| p.1 == id).map(|p| p.0).collect();
+        assert_eq!(reader.out_neighbors(&triple, id).unwrap(), out);
+        assert_eq!(reader.out_degree(&triple, id).unwrap(), out.len());
+
+        let mut found = reader.in_neighbors(&triple, id).unwrap();
+        found.sort_unstable();
+        inn.sort_unstable();
+        assert_eq!(found, inn);
+        assert_eq!(reader.in_degree(&triple, id).unwrap(), inn.len());
+    }
+
+    let missing = EdgeTriple::new("person", "likes", "person");
+    assert!(reader.out_neighbors(&missing, 0).is_err());
+}
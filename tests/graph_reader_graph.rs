@@ -0,0 +1,58 @@
+mod common;
+
+use graphar::{
+    graph_info::AdjListType,
+    graph_reader::{EdgeTriple, Graph},
+};
+
+fn modern_graph() -> Graph {
+    let path = common::test_data_root()
+        .join("modern_graph")
+        .join("modern_graph.graph.yml");
+    Graph::open(path).unwrap()
+}
+
+#[test]
+fn graph_enumerates_types() {
+    let graph = modern_graph();
+    let mut vertex_types = graph.vertex_types();
+    vertex_types.sort();
+    assert_eq!(vertex_types, vec!["person", "software"]);
+
+    let triples = graph.edge_triples();
+    assert!(triples.contains(&EdgeTriple::new("person", "knows", "person")));
+    assert!(triples.contains(&EdgeTriple::new("person", "created", "software")));
+}
+
+#[test]
+fn graph_opens_and_caches_collections() {
+    let mut graph = modern_graph();
+    assert_eq!(graph.vertices("person").unwrap().len(), 4);
+    let names: Vec<String> = graph
+        .vertices("person")
+        .unwrap()
+        .begin()
+        .map(|v| v.property::<String>("name").unwrap())
+        .collect();
+    assert_eq!(names.len(), 4);
+    assert!(graph.vertices("robot").is_err());
+
+    let triple = EdgeTriple::new("person", "knows", "person");
+    assert_eq!(
+        graph.adj_list_type(&triple).unwrap(),
+        AdjListType::OrderedBySource
+    );
+    let len = graph.edges("person", "knows", "person").unwrap().len();
+    assert!(len > 0);
+    let sources: Vec<i64> = graph
+        .edges("person", "knows", "person")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.src())
+        .collect();
+    assert_eq!(sources.len(), len);
+    assert!(graph.edges("person", "likes", "person").is_err());
+
+    let degree = graph.neighbors().out_degree(&triple, sources[0]).unwrap();
+    assert!(degree > 0);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use graphar::{
+    graph_info::{AdjListType, GraphInfo},
+    graph_reader::{Edges, Vertices, col},
+};
+use rayon::prelude::*;
+
+fn ldbc_sample() -> GraphInfo {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("csv")
+        .join("ldbc_sample.graph.yml");
+    GraphInfo::load(path).unwrap()
+}
+
+#[test]
+fn vertices_par_chunks_cover_collection() {
+    let gi = ldbc_sample();
+    let vertices = Vertices::new(&gi, "person").unwrap();
+    let chunk_size = gi.vertex_info("person").unwrap().chunk_size();
+
+    let mut ids: Vec<i64> = vertices
+        .par_chunks()
+        .flat_map_iter(|chunk| chunk.unwrap().map(|v| v.id()))
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, (0..vertices.len() as i64).collect::<Vec<_>>());
+    assert_eq!(
+        vertices.par_chunks().len() as i64,
+        (vertices.len() as i64 + chunk_size - 1) / chunk_size
+    );
+
+    let males = Vertices::with_property(&gi, "person", "gender", col("gender").eq("male")).unwrap();
+    let genders: Vec<String> = males
+        .par_chunks()
+        .flat_map_iter(|chunk| {
+            chunk
+                .unwrap()
+                .map(|v| v.property::<String>("gender").unwrap())
+        })
+        .collect();
+    assert_eq!(genders.len(), males.len());
+    assert!(genders.iter().all(|g| g == "male"));
+}
+
+#[test]
+fn edges_par_chunks_cover_collection() {
+    let gi = ldbc_sample();
+    for vertex_chunks in [None, Some(0..1)] {
+        let edges = Edges::new(
+            &gi,
+            "person",
+            "knows",
+            "person",
+            AdjListType::OrderedBySource,
+            vertex_chunks,
+        )
+        .unwrap();
+        let count: usize = edges
+            .par_chunks()
+            .unwrap()
+            .map(|chunk| chunk.unwrap().count())
+            .sum();
+        assert_eq!(count, edges.len());
+    }
+}
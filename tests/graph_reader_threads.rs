@@ -0,0 +1,67 @@
+mod common;
+
+use std::thread;
+
+use graphar::{
+    graph_info::{AdjListType, GraphInfo},
+    graph_reader::{Edge, EdgeCursor, EdgeIter, Edges, Graph, Vertex, VertexIter, Vertices},
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+fn assert_send<T: Send>() {}
+
+#[test]
+fn readers_are_send_and_sync() {
+    assert_send_sync::<GraphInfo>();
+    assert_send_sync::<Vertices>();
+    assert_send_sync::<Edges>();
+    assert_send_sync::<Vertex>();
+    assert_send_sync::<Edge>();
+    assert_send_sync::<Graph>();
+    assert_send::<VertexIter>();
+    assert_send::<EdgeIter>();
+    assert_send::<EdgeCursor>();
+}
+
+#[test]
+fn concurrent_readers_share_collections() {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("csv")
+        .join("ldbc_sample.graph.yml");
+    let gi = GraphInfo::load(path).unwrap();
+    let vertices = Vertices::new(&gi, "person").unwrap();
+    let edges = Edges::new(
+        &gi,
+        "person",
+        "knows",
+        "person",
+        AdjListType::OrderedBySource,
+        None,
+    )
+    .unwrap();
+
+    let expected_names: Vec<String> = vertices
+        .begin()
+        .map(|v| v.property::<String>("firstName").unwrap())
+        .collect();
+    let expected_edges = edges.len();
+
+    thread::scope(|scope| {
+        for i in 0..4 {
+            // Shared by reference and by clone, both reach the same collection.
+            let vertices = &vertices;
+            let edges = edges.clone();
+            let expected_names = &expected_names;
+            scope.spawn(move || {
+                let names: Vec<String> = vertices
+                    .begin()
+                    .map(|v| v.property::<String>("firstName").unwrap())
+                    .collect();
+                assert_eq!(&names, expected_names);
+                assert_eq!(vertices.find(i).id(), i);
+                assert_eq!(edges.begin().count(), expected_edges);
+            });
+        }
+    });
+}
@@ -1,7 +1,7 @@
 mod common;
 
 use graphar::graph_info::GraphInfo;
-use graphar::graph_reader::Vertices;
+use graphar::graph_reader::{LabelMatch, VertexFilter, Vertices, col};
 
 #[test]
 fn vertices_with_label_and_labels_ldbc_parquet() {
@@ -12,7 +12,7 @@ fn vertices_with_label_and_labels_ldbc_parquet() {
     let graph_info = GraphInfo::load(path).unwrap();
 
     // With label: organisation::university
-    let mut org_univ = Vertices::with_label(&graph_info, "organisation", "university").unwrap();
+    let org_univ = Vertices::with_label(&graph_info, "organisation", "university").unwrap();
     assert!(!org_univ.is_empty());
     let mut it = org_univ.begin();
     let org_labels = it.labels().unwrap();
@@ -22,3 +22,125 @@ fn vertices_with_label_and_labels_ldbc_parquet() {
     let labels = it.labels().unwrap();
     assert!(labels.iter().any(|l| l == "university" || l == "company" || l == "public"));
 }
+
+#[test]
+fn vertices_with_any_and_all_labels_ldbc_parquet() {
+    let path = common::test_data_root()
+        .join("ldbc")
+        .join("parquet")
+        .join("ldbc.graph.yml");
+    let graph_info = GraphInfo::load(path).unwrap();
+
+    let university = Vertices::with_label(&graph_info, "organisation", "university").unwrap();
+    let company = Vertices::with_label(&graph_info, "organisation", "company").unwrap();
+
+    let any = Vertices::with_labels(
+        &graph_info,
+        "organisation",
+        &["university", "company"],
+        LabelMatch::Any,
+    )
+    .unwrap();
+    assert!(any.len() >= university.len().max(company.len()));
+    assert!(any.len() <= university.len() + company.len());
+    let mut count = 0;
+    let mut it = any.begin();
+    while it != any.end() {
+        let labels = it.labels().unwrap();
+        assert!(labels.iter().any(|l| l == "university" || l == "company"));
+        count += 1;
+        it.advance();
+    }
+    assert_eq!(count, any.len());
+    assert_eq!(any.begin().count(), any.len());
+
+    let all = Vertices::with_labels(
+        &graph_info,
+        "organisation",
+        &["university", "public"],
+        LabelMatch::All,
+    )
+    .unwrap();
+    assert!(all.len() <= university.len());
+    let mut it = all.begin();
+    while it != all.end() {
+        let labels = it.labels().unwrap();
+        assert!(labels.iter().any(|l| l == "university"));
+        assert!(labels.iter().any(|l| l == "public"));
+        it.advance();
+    }
+}
+
+#[test]
+fn vertices_with_property_ldbc_sample_parquet() {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("parquet")
+        .join("ldbc_sample.graph.yml");
+    let graph_info = GraphInfo::load(path).unwrap();
+
+    let males =
+        Vertices::with_property(&graph_info, "person", "gender", col("gender").eq("male")).unwrap();
+    assert_eq!(males.len(), 449);
+    let genders: Vec<String> = males
+        .begin()
+        .map(|v| v.property::<String>("gender").unwrap())
+        .collect();
+    assert_eq!(genders.len(), 449);
+    assert!(genders.iter().all(|g| g == "male"));
+}
+
+#[test]
+fn vertices_filter_ids_compose_ldbc_sample_parquet() {
+    let path = common::test_data_root()
+        .join("ldbc_sample")
+        .join("parquet")
+        .join("ldbc_sample.graph.yml");
+    let graph_info = GraphInfo::load(path).unwrap();
+    let persons = Vertices::new(&graph_info, "person").unwrap();
+    let chunk_size = graph_info.vertex_info("person").unwrap().chunk_size();
+
+    let male = VertexFilter::property("gender", col("gender").eq("male"));
+    let males = persons.filter_ids(&male).unwrap();
+    assert_eq!(males.len(), 449);
+    assert!(males.ids().windows(2).all(|w| w[0] < w[1]));
+    for id in males.ids() {
+        assert!(males.chunks().binary_search(&(id / chunk_size)).is_ok());
+    }
+
+    // A follow-up filter only sees the surviving vertices.
+    let male_persons = persons.filter(&male).unwrap();
+    assert_eq!(male_persons.filter_ids(&male).unwrap(), males);
+    let female = VertexFilter::property("gender", col("gender").eq("female"));
+    assert!(male_persons.filter_ids(&female).unwrap().is_empty());
+}
+
+#[test]
+fn vertices_filter_any_labels_ldbc_parquet() {
+    let path = common::test_data_root()
+        .join("ldbc")
+        .join("parquet")
+        .join("ldbc.graph.yml");
+    let graph_info = GraphInfo::load(path).unwrap();
+
+    let university = Vertices::with_label(&graph_info, "organisation", "university").unwrap();
+    let any = Vertices::with_labels(
+        &graph_info,
+        "organisation",
+        &["university", "company"],
+        LabelMatch::Any,
+    )
+    .unwrap();
+    let refined = any
+        .filter_ids(&VertexFilter::labels(&["university"]))
+        .unwrap();
+    assert_eq!(refined.len(), university.len());
+    assert_eq!(
+        refined,
+        university
+            .filter_ids(&VertexFilter::labels(&["university"]))
+            .unwrap()
+    );
+    let filtered = any.filter(&VertexFilter::labels(&["university"])).unwrap();
+    assert_eq!(filtered.len(), university.len());
+}
@@ -1,6 +1,12 @@
 mod common;
 
-use graphar::{graph_info::GraphInfo, graph_reader::Vertices};
+use graphar::{
+    graph_builder::{VertexBuilder, VerticesBuilder},
+    graph_info::{Cardinality, DataType, FileType, GraphInfo, Property},
+    graph_reader::Vertices,
+    types::PropertyValue,
+};
+use tempfile::tempdir;
 
 #[test]
 fn vertices_iterate_and_read_properties_modern_graph() {
@@ -9,7 +15,7 @@ fn vertices_iterate_and_read_properties_modern_graph() {
         .join("modern_graph.graph.yml");
     let gi = GraphInfo::load(path).unwrap();
 
-    let mut vertices = Vertices::new(&gi, "person").unwrap();
+    let vertices = Vertices::new(&gi, "person").unwrap();
     assert!(!vertices.is_empty());
     assert_eq!(vertices.len(), 4);
 
@@ -42,10 +48,87 @@ fn vertices_iterate_and_read_properties_modern_graph() {
     // After iterating len() times, iterator equals end
     let mut it = vertices.begin();
     for _ in 0..vertices.len() {
-        it.next();
+        it.advance();
     }
     let end = vertices.end();
     assert!(it == end);
 
     // Skip labels() due to missing label data in this fixture
 }
+
+#[test]
+fn vertices_optional_property_reads_modern_graph() {
+    let path = common::test_data_root()
+        .join("modern_graph")
+        .join("modern_graph.graph.yml");
+    let gi = GraphInfo::load(path).unwrap();
+    let vertices = Vertices::new(&gi, "person").unwrap();
+
+    let mut iter = vertices.begin();
+    assert_eq!(
+        iter.property::<Option<String>>("name").unwrap(),
+        Some("vadas".to_string())
+    );
+    let v0 = iter.vertex();
+    assert_eq!(v0.property::<Option<i64>>("age").unwrap(), Some(27));
+    assert!(v0.property::<Option<i64>>("missing").is_err());
+}
+
+#[test]
+fn vertices_optional_property_reads_null() {
+    let vertex_info = common::vertex_info(
+        vec![
+            Property::new("id", &DataType::int64(), true, false, Cardinality::Single),
+            Property::new("age", &DataType::int64(), false, true, Cardinality::Single),
+        ],
+        FileType::Parquet,
+    );
+
+    // vadas has an age, the second vertex a null cell
+    let tmp = tempdir().unwrap();
+    let prefix = format!("{}/", tmp.path().display());
+    let mut vb = VerticesBuilder::new(&vertex_info, &prefix, 0).unwrap();
+    let mut v = VertexBuilder::new();
+    v.add_property("id".into(), 0_i64);
+    v.add_property("age".into(), 27_i64);
+    vb.add_vertex(v).unwrap();
+    let mut v = VertexBuilder::new();
+    v.add_property("id".into(), 1_i64);
+    v.add_null("age".into());
+    vb.add_vertex(v).unwrap();
+    vb.dump().unwrap();
+
+    let gi = GraphInfo::new("g", &vec![vertex_info], &vec![], &vec![], &prefix, None).unwrap();
+    let vertices = Vertices::new(&gi, "person").unwrap();
+    let mut iter = vertices.begin();
+    assert!(matches!(iter.property::<Option<i64>>("age"), Ok(Some(27))));
+    iter.advance();
+    assert!(matches!(iter.property::<Option<i64>>("age"), Ok(None)));
+    let v1 = iter.vertex();
+    assert!(matches!(v1.property::<Option<i64>>("age"), Ok(None)));
+    assert!(matches!(v1.property::<Option<i64>>("id"), Ok(Some(1))));
+    assert_eq!(v1.get("age").unwrap(), PropertyValue::Null);
+}
+
+#[test]
+fn vertices_dynamic_property_values_modern_graph() {
+    let path = common::test_data_root()
+        .join("modern_graph")
+        .join("modern_graph.graph.yml");
+    let gi = GraphInfo::load(path).unwrap();
+    let vertices = Vertices::new(&gi, "person").unwrap();
+
+    let mut iter = vertices.begin();
+    assert_eq!(
+        iter.get("name").unwrap(),
+        PropertyValue::String("vadas".into())
+    );
+    let v0 = iter.vertex();
+    assert_eq!(v0.get("age").unwrap(), PropertyValue::Int64(27));
+    assert!(v0.get("missing").is_err());
+
+    let props = v0.properties().unwrap();
+    let names: Vec<&str> = props.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["id", "name", "age"]);
+    assert_eq!(props[0].1, PropertyValue::Int64(2));
+}